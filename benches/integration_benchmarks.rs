@@ -0,0 +1,58 @@
+//! Benchmarks for integration registry lookups.
+//!
+//! Compares the linear scan `integrations info` used to do against the
+//! `registry::name_index()` lookup, on a synthetic 500-entry registry.
+//!
+//! Run: `cargo bench --bench integration_benchmarks`
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+use zeroclaw::integrations::registry;
+use zeroclaw::integrations::{
+    IntegrationCategory, IntegrationEntry, IntegrationStatus, PricingModel,
+};
+
+const SYNTHETIC_ENTRIES: usize = 500;
+
+fn synthetic_registry() -> &'static [IntegrationEntry] {
+    let entries: Vec<_> = (0..SYNTHETIC_ENTRIES)
+        .map(|i| {
+            IntegrationEntry::builder()
+                .name(Box::leak(format!("Integration {i}").into_boxed_str()))
+                .description("Synthetic benchmark entry")
+                .category(IntegrationCategory::ToolsAutomation)
+                .license("MIT")
+                .pricing_model(PricingModel::OpenSource)
+                .status_fn(|_| IntegrationStatus::Available)
+                .build()
+        })
+        .collect();
+    Box::leak(entries.into_boxed_slice())
+}
+
+fn bench_name_lookup(c: &mut Criterion) {
+    let entries = synthetic_registry();
+    let index = registry::build_name_index(entries);
+    // Worst case for the scan: the last entry, queried with different casing.
+    let query = format!("INTEGRATION {}", SYNTHETIC_ENTRIES - 1);
+
+    let mut group = c.benchmark_group("integration_name_lookup");
+    group.bench_function("linear_scan_500", |b| {
+        b.iter(|| {
+            let name_lower = black_box(&query).to_lowercase();
+            entries.iter().find(|e| e.name.to_lowercase() == name_lower)
+        });
+    });
+    group.bench_function("name_index_500", |b| {
+        b.iter(|| {
+            index
+                .get(black_box(&query).to_lowercase().as_str())
+                .copied()
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_name_lookup);
+criterion_main!(benches);
@@ -13,7 +13,7 @@ use std::time::Duration;
 use tokio::fs;
 
 /// Telegram's maximum message length for text messages
-const TELEGRAM_MAX_MESSAGE_LENGTH: usize = 4096;
+pub(crate) const TELEGRAM_MAX_MESSAGE_LENGTH: usize = 4096;
 /// Reserve space for continuation markers added by send_text_chunks:
 /// worst case is "(continued)\n\n" + chunk + "\n\n(continues...)" = 30 extra chars
 const TELEGRAM_CONTINUATION_OVERHEAD: usize = 30;
@@ -200,6 +200,15 @@ fn format_attachment_content(
     }
 }
 
+/// Numeric bot ID from a `<bot_id>:<secret>` Bot API token.
+pub(crate) fn parse_bot_id(token: &str) -> Option<u64> {
+    let (id, secret) = token.trim().split_once(':')?;
+    if secret.is_empty() {
+        return None;
+    }
+    id.parse().ok()
+}
+
 fn is_http_url(target: &str) -> bool {
     target.starts_with("http://") || target.starts_with("https://")
 }
@@ -3260,6 +3269,24 @@ mod tests {
     use super::*;
     use std::path::Path;
 
+    #[test]
+    fn parse_bot_id_reads_numeric_prefix() {
+        assert_eq!(
+            parse_bot_id("123456789:AAHdqTcvCH1vGWJxfSeofSAs0K5PALDsaw"),
+            Some(123_456_789)
+        );
+        assert_eq!(parse_bot_id(" 42:abc \n"), Some(42));
+    }
+
+    #[test]
+    fn parse_bot_id_rejects_malformed_tokens() {
+        assert_eq!(parse_bot_id(""), None);
+        assert_eq!(parse_bot_id("123456789"), None);
+        assert_eq!(parse_bot_id("123456789:"), None);
+        assert_eq!(parse_bot_id("bot:secret"), None);
+        assert_eq!(parse_bot_id("-5:secret"), None);
+    }
+
     #[cfg(unix)]
     fn symlink_file(src: &Path, dst: &Path) {
         std::os::unix::fs::symlink(src, dst).expect("symlink should be created");
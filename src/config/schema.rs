@@ -283,6 +283,10 @@ pub struct Config {
     /// WASM plugin engine configuration (`[wasm]` section).
     #[serde(default)]
     pub wasm: WasmConfig,
+
+    /// Integration catalog preferences (`[integrations]` section).
+    #[serde(default)]
+    pub integrations: IntegrationsConfig,
 }
 
 /// Named provider profile definition compatible with Codex app-server style config.
@@ -311,6 +315,50 @@ pub struct ProviderConfig {
     pub reasoning_level: Option<String>,
 }
 
+/// Integration catalog preferences (`[integrations]` section).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+pub struct IntegrationsConfig {
+    /// Favorites listed first by `zeroclaw integrations list` (`[integrations.pinned]`).
+    #[serde(default)]
+    pub pinned: PinnedIntegrationsConfig,
+    /// Per-integration overrides (`[integrations.<name>]`), keyed by integration name.
+    /// Only read by the `zeroclaw integrations` doctor/test/TLS checks; provider and
+    /// channel endpoints keep using their own sections.
+    #[serde(default, flatten)]
+    pub overrides: HashMap<String, IntegrationOverrideConfig>,
+}
+
+impl IntegrationsConfig {
+    /// The `base_url` override for integration `name` (case-insensitive), if set.
+    pub fn base_url(&self, name: &str) -> Option<&str> {
+        self.overrides
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .and_then(|(_, entry)| entry.base_url.as_deref())
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+    }
+}
+
+/// Overrides for one integration (`[integrations.<name>]` section).
+///
+/// Unknown keys are rejected so they are not silently dropped when the config is saved.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(deny_unknown_fields)]
+pub struct IntegrationOverrideConfig {
+    /// Self-hosted endpoint replacing the integration's default API base URL.
+    #[serde(default)]
+    pub base_url: Option<String>,
+}
+
+/// Pinned integrations (`[integrations.pinned]` section).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+pub struct PinnedIntegrationsConfig {
+    /// Integration names, in display order. Managed by `zeroclaw integrations pin/unpin`.
+    #[serde(default)]
+    pub names: Vec<String>,
+}
+
 // ── Delegate Agents ──────────────────────────────────────────────
 
 /// Configuration for a delegate sub-agent used by the `delegate` tool.
@@ -5064,6 +5112,7 @@ impl Default for Config {
             mcp: McpConfig::default(),
             model_support_vision: None,
             wasm: WasmConfig::default(),
+            integrations: IntegrationsConfig::default(),
         }
     }
 }
@@ -5854,6 +5903,27 @@ fn validate_mcp_config(config: &McpConfig) -> Result<()> {
 }
 
 impl Config {
+    /// Re-read config from disk, resolving the same directories as startup.
+    ///
+    /// Used by long-running views (e.g. `integrations list --live`) that
+    /// should pick up edits made while they run.
+    pub async fn reload() -> Result<Self> {
+        Self::load_or_init().await
+    }
+
+    /// API base URL for integration `name`: the `[integrations.<name>] base_url`
+    /// override, else the registry default. `None` if neither is set. Only the
+    /// `zeroclaw integrations` checks use this; providers and channels do not.
+    pub fn integration_base_url(&self, name: &str) -> Option<String> {
+        let url = match self.integrations.base_url(name) {
+            Some(url) => url,
+            None => crate::integrations::registry::find(name)
+                .map(|entry| entry.api_base_url)
+                .filter(|url| !url.is_empty())?,
+        };
+        Some(url.trim_end_matches('/').to_string())
+    }
+
     pub async fn load_or_init() -> Result<Self> {
         let (default_zeroclaw_dir, default_workspace_dir) = default_config_and_workspace_dirs()?;
 
@@ -7452,6 +7522,50 @@ recipient = "42"
         assert_eq!(parsed.max_run_history, 100);
     }
 
+    #[test]
+    async fn integration_base_url_override_is_picked_up() {
+        let toml_str = r#"
+default_temperature = 0.7
+
+[integrations.pinned]
+names = ["Ollama"]
+
+[integrations.ollama]
+base_url = "http://gpu-box:11434/"
+"#;
+
+        let parsed: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(parsed.integrations.pinned.names, ["Ollama"]);
+        assert_eq!(
+            parsed.integration_base_url("Ollama").as_deref(),
+            Some("http://gpu-box:11434")
+        );
+        assert_eq!(
+            Config::default().integration_base_url("Ollama").as_deref(),
+            Some("http://localhost:11434")
+        );
+        assert_eq!(parsed.integration_base_url("Shell"), None);
+
+        let round_trip: Config = toml::from_str(&toml::to_string(&parsed).unwrap()).unwrap();
+        assert_eq!(
+            round_trip.integrations.base_url("OLLAMA"),
+            Some("http://gpu-box:11434/")
+        );
+    }
+
+    #[test]
+    async fn integration_override_rejects_unknown_keys() {
+        let toml_str = r#"
+default_temperature = 0.7
+
+[integrations.github]
+token = "ghp_example"
+"#;
+
+        let err = toml::from_str::<Config>(toml_str).unwrap_err();
+        assert!(err.to_string().contains("token"), "{err}");
+    }
+
     #[test]
     async fn config_defaults_cron_when_section_missing() {
         let toml_str = r#"
@@ -7613,6 +7727,7 @@ default_temperature = 0.7
             mcp: McpConfig::default(),
             model_support_vision: None,
             wasm: WasmConfig::default(),
+            integrations: IntegrationsConfig::default(),
         };
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
@@ -7984,6 +8099,7 @@ tool_dispatcher = "xml"
             mcp: McpConfig::default(),
             model_support_vision: None,
             wasm: WasmConfig::default(),
+            integrations: IntegrationsConfig::default(),
         };
 
         config.save().await.unwrap();
@@ -323,7 +323,7 @@ pub async fn handle_api_integrations(
     let integrations: Vec<serde_json::Value> = entries
         .iter()
         .map(|entry| {
-            let status = (entry.status_fn)(&config);
+            let status = entry.status(&config);
             serde_json::json!({
                 "name": entry.name,
                 "description": entry.description,
@@ -333,7 +333,11 @@ pub async fn handle_api_integrations(
         })
         .collect();
 
-    Json(serde_json::json!({"integrations": integrations})).into_response()
+    Json(serde_json::json!({
+        "integrations": integrations,
+        "registry_checksum": crate::integrations::registry::checksum(),
+    }))
+    .into_response()
 }
 
 /// POST /api/doctor — run diagnostics
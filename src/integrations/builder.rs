@@ -0,0 +1,307 @@
+use super::{
+    AuthType, ConnectionTestFn, EnvVarSpec, HealthCheckFn, IntegrationCategory, IntegrationEntry,
+    IntegrationStatus, Maturity, MessageType, Platform, PricingModel, StatusFn,
+};
+use crate::config::Config;
+
+/// `const` builder for [`IntegrationEntry`], so registry entries only spell
+/// out what differs from the defaults and can live in a `static`.
+///
+/// `name`, `description`, `category`, `license`, `pricing_model` and
+/// `status_fn` are required: `build()` panics without them, which is a
+/// compile error when the builder is evaluated in a `const` or `static`.
+#[derive(Clone, Copy)]
+pub struct IntegrationEntryBuilder {
+    name: Option<&'static str>,
+    description: Option<&'static str>,
+    category: Option<IntegrationCategory>,
+    license: Option<&'static str>,
+    maturity: Maturity,
+    pricing_model: Option<PricingModel>,
+    platform_support: &'static [Platform],
+    max_message_size: Option<usize>,
+    supported_message_types: &'static [MessageType],
+    system_dependencies: &'static [&'static str],
+    example_command: Option<&'static str>,
+    api_base_url: &'static str,
+    self_hosted: bool,
+    authentication_type: AuthType,
+    data_residency: &'static str,
+    environment_variables: &'static [EnvVarSpec],
+    required_config_keys: &'static [&'static str],
+    optional_config_keys: &'static [&'static str],
+    tags: &'static [&'static str],
+    contact_email: &'static str,
+    changelog_url: &'static str,
+    source_module: Option<&'static str>,
+    health_check_fn: Option<HealthCheckFn>,
+    test_fn: Option<ConnectionTestFn>,
+    status_fn: Option<fn(&Config) -> IntegrationStatus>,
+}
+
+impl IntegrationEntry {
+    pub const fn builder() -> IntegrationEntryBuilder {
+        IntegrationEntryBuilder::new()
+    }
+}
+
+impl Default for IntegrationEntryBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntegrationEntryBuilder {
+    /// A stable, cross-platform, text-only entry with no config keys, tags,
+    /// health check or known data residency.
+    pub const fn new() -> Self {
+        Self {
+            name: None,
+            description: None,
+            category: None,
+            license: None,
+            maturity: Maturity::Stable,
+            pricing_model: None,
+            platform_support: &[Platform::Any],
+            max_message_size: None,
+            supported_message_types: &[MessageType::Text],
+            system_dependencies: &[],
+            example_command: None,
+            api_base_url: "",
+            self_hosted: false,
+            authentication_type: AuthType::None,
+            data_residency: "Unknown",
+            environment_variables: &[],
+            required_config_keys: &[],
+            optional_config_keys: &[],
+            tags: &[],
+            contact_email: "",
+            changelog_url: "",
+            source_module: None,
+            health_check_fn: None,
+            test_fn: None,
+            status_fn: None,
+        }
+    }
+
+    pub const fn name(mut self, name: &'static str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub const fn description(mut self, description: &'static str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub const fn category(mut self, category: IntegrationCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    pub const fn license(mut self, license: &'static str) -> Self {
+        self.license = Some(license);
+        self
+    }
+
+    pub const fn maturity(mut self, maturity: Maturity) -> Self {
+        self.maturity = maturity;
+        self
+    }
+
+    pub const fn pricing_model(mut self, pricing_model: PricingModel) -> Self {
+        self.pricing_model = Some(pricing_model);
+        self
+    }
+
+    pub const fn platform_support(mut self, platforms: &'static [Platform]) -> Self {
+        self.platform_support = platforms;
+        self
+    }
+
+    pub const fn max_message_size(mut self, limit: usize) -> Self {
+        self.max_message_size = Some(limit);
+        self
+    }
+
+    pub const fn supported_message_types(mut self, types: &'static [MessageType]) -> Self {
+        self.supported_message_types = types;
+        self
+    }
+
+    pub const fn system_dependencies(mut self, binaries: &'static [&'static str]) -> Self {
+        self.system_dependencies = binaries;
+        self
+    }
+
+    pub const fn example_command(mut self, command: &'static str) -> Self {
+        self.example_command = Some(command);
+        self
+    }
+
+    pub const fn api_base_url(mut self, url: &'static str) -> Self {
+        self.api_base_url = url;
+        self
+    }
+
+    pub const fn self_hosted(mut self, self_hosted: bool) -> Self {
+        self.self_hosted = self_hosted;
+        self
+    }
+
+    pub const fn authentication_type(mut self, auth: AuthType) -> Self {
+        self.authentication_type = auth;
+        self
+    }
+
+    pub const fn data_residency(mut self, region: &'static str) -> Self {
+        self.data_residency = region;
+        self
+    }
+
+    pub const fn environment_variables(mut self, vars: &'static [EnvVarSpec]) -> Self {
+        self.environment_variables = vars;
+        self
+    }
+
+    pub const fn required_config_keys(mut self, keys: &'static [&'static str]) -> Self {
+        self.required_config_keys = keys;
+        self
+    }
+
+    pub const fn optional_config_keys(mut self, keys: &'static [&'static str]) -> Self {
+        self.optional_config_keys = keys;
+        self
+    }
+
+    pub const fn tags(mut self, tags: &'static [&'static str]) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub const fn contact_email(mut self, email: &'static str) -> Self {
+        self.contact_email = email;
+        self
+    }
+
+    pub const fn changelog_url(mut self, url: &'static str) -> Self {
+        self.changelog_url = url;
+        self
+    }
+
+    pub const fn source_module(mut self, module: &'static str) -> Self {
+        self.source_module = Some(module);
+        self
+    }
+
+    pub const fn health_check_fn(mut self, check: HealthCheckFn) -> Self {
+        self.health_check_fn = Some(check);
+        self
+    }
+
+    pub const fn test_fn(mut self, test: ConnectionTestFn) -> Self {
+        self.test_fn = Some(test);
+        self
+    }
+
+    /// `status_fn` must not panic; return `Degraded` for a failed check.
+    pub const fn status_fn(mut self, status_fn: fn(&Config) -> IntegrationStatus) -> Self {
+        self.status_fn = Some(status_fn);
+        self
+    }
+
+    /// Finish the entry, panicking if a required field was never set.
+    pub const fn build(self) -> IntegrationEntry {
+        let Some(name) = self.name else {
+            panic!("IntegrationEntry::builder() is missing name()");
+        };
+        let Some(description) = self.description else {
+            panic!("IntegrationEntry::builder() is missing description()");
+        };
+        let Some(category) = self.category else {
+            panic!("IntegrationEntry::builder() is missing category()");
+        };
+        let Some(license) = self.license else {
+            panic!("IntegrationEntry::builder() is missing license()");
+        };
+        let Some(pricing_model) = self.pricing_model else {
+            panic!("IntegrationEntry::builder() is missing pricing_model()");
+        };
+        let Some(status_fn) = self.status_fn else {
+            panic!("IntegrationEntry::builder() is missing status_fn()");
+        };
+
+        IntegrationEntry {
+            name,
+            description,
+            category,
+            license,
+            maturity: self.maturity,
+            pricing_model,
+            platform_support: self.platform_support,
+            max_message_size: self.max_message_size,
+            supported_message_types: self.supported_message_types,
+            system_dependencies: self.system_dependencies,
+            example_command: self.example_command,
+            api_base_url: self.api_base_url,
+            self_hosted: self.self_hosted,
+            authentication_type: self.authentication_type,
+            data_residency: self.data_residency,
+            environment_variables: self.environment_variables,
+            required_config_keys: self.required_config_keys,
+            optional_config_keys: self.optional_config_keys,
+            tags: self.tags,
+            contact_email: self.contact_email,
+            changelog_url: self.changelog_url,
+            source_module: self.source_module,
+            health_check_fn: self.health_check_fn,
+            test_fn: self.test_fn,
+            status_fn: StatusFn::Static(status_fn),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static STATIC_ENTRY: IntegrationEntry = IntegrationEntry::builder()
+        .name("Static")
+        .description("Built in a static")
+        .category(IntegrationCategory::ToolsAutomation)
+        .license("MIT")
+        .pricing_model(PricingModel::OpenSource)
+        .tags(&["offline"])
+        .status_fn(|_| IntegrationStatus::Available)
+        .build();
+
+    #[test]
+    fn builder_fills_defaults_in_static_context() {
+        assert_eq!(STATIC_ENTRY.name, "Static");
+        assert_eq!(STATIC_ENTRY.tags, ["offline"]);
+        assert_eq!(STATIC_ENTRY.maturity, Maturity::Stable);
+        assert_eq!(STATIC_ENTRY.platform_support, [Platform::Any]);
+        assert_eq!(STATIC_ENTRY.supported_message_types, [MessageType::Text]);
+        assert_eq!(STATIC_ENTRY.authentication_type, AuthType::None);
+        assert_eq!(STATIC_ENTRY.data_residency, "Unknown");
+        assert!(STATIC_ENTRY.health_check_fn.is_none());
+        assert!(STATIC_ENTRY.test_fn.is_none());
+        assert!(STATIC_ENTRY.source_module.is_none());
+        assert_eq!(
+            STATIC_ENTRY.status(&Config::default()),
+            IntegrationStatus::Available
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "missing status_fn()")]
+    fn build_panics_without_required_fields() {
+        let _ = IntegrationEntry::builder()
+            .name("Incomplete")
+            .description("No status_fn")
+            .category(IntegrationCategory::Chat)
+            .license("MIT")
+            .pricing_model(PricingModel::Free)
+            .build();
+    }
+}
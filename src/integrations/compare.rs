@@ -0,0 +1,57 @@
+use super::{resolve_status, IntegrationEntry};
+use crate::config::Config;
+use console::{pad_str, Alignment};
+use std::fmt::Write;
+
+/// Width of each value column in the comparison table
+const COLUMN_WIDTH: usize = 28;
+
+/// Render the fields of `a` and `b` as a two-column table, marking rows
+/// whose values differ with `≠`.
+pub fn side_by_side(a: &IntegrationEntry, b: &IntegrationEntry, config: &Config) -> String {
+    let rows = [
+        ("Category", a.category.to_string(), b.category.to_string()),
+        (
+            "Status",
+            resolve_status(a, config).to_string(),
+            resolve_status(b, config).to_string(),
+        ),
+        (
+            "Maturity",
+            a.maturity.label().to_string(),
+            b.maturity.label().to_string(),
+        ),
+        ("License", a.license.to_string(), b.license.to_string()),
+    ];
+
+    let cell = |text: &str| pad_str(text, COLUMN_WIDTH, Alignment::Left, Some("…")).into_owned();
+
+    let mut out = String::new();
+    let _ = writeln!(out, "  {:<10} {} {}", "", cell(a.name), cell(b.name));
+    for (field, left, right) in rows {
+        let marker = if left == right { " " } else { "≠" };
+        let _ = writeln!(out, "{marker} {field:<10} {} {}", cell(&left), cell(&right));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::registry;
+
+    #[test]
+    fn side_by_side_lists_both_names_and_marks_differences() {
+        let config = Config::default();
+        let entries = registry::all_integrations();
+        let shell = entries.iter().find(|e| e.name == "Shell").unwrap();
+        let browser = entries.iter().find(|e| e.name == "Browser").unwrap();
+
+        let out = side_by_side(shell, browser, &config);
+        assert!(out.contains("Shell"));
+        assert!(out.contains("Browser"));
+        assert!(out
+            .lines()
+            .any(|line| line.starts_with('≠') && line.contains("Maturity")));
+    }
+}
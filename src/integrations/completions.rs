@@ -0,0 +1,159 @@
+use super::{IntegrationEntry, IntegrationError};
+use clap_complete::Shell;
+use std::fmt::Write;
+
+/// Completion script that offers every name in `entries` after
+/// `zeroclaw integrations info`. Bash and zsh fall back to the regular
+/// `zeroclaw completions` function everywhere else.
+pub fn script(shell: Shell, entries: &[&IntegrationEntry]) -> Result<String, IntegrationError> {
+    match shell {
+        Shell::Bash => Ok(bash(entries)),
+        Shell::Zsh => Ok(zsh(entries)),
+        Shell::Fish => Ok(fish(entries)),
+        other => Err(IntegrationError::invalid_option(
+            "shell",
+            &other.to_string(),
+            &["bash", "zsh", "fish"],
+        )),
+    }
+}
+
+/// `'text'` with embedded single quotes closed, escaped and reopened.
+fn single_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', r"'\''"))
+}
+
+fn bash(entries: &[&IntegrationEntry]) -> String {
+    let names: Vec<&str> = entries.iter().map(|e| e.name).collect();
+    let mut out = String::from("_zeroclaw_integrations() {\n");
+    out.push_str(
+        "    if [[ ${COMP_WORDS[1]} == integrations && ${COMP_WORDS[2]} == info && $COMP_CWORD -eq 3 ]]; then\n",
+    );
+    out.push_str("        local IFS=$'\\n'\n");
+    let _ = writeln!(
+        out,
+        "        COMPREPLY=($(compgen -W {} -- \"${{COMP_WORDS[COMP_CWORD]}}\"))",
+        single_quote(&names.join("\n"))
+    );
+    out.push_str("        return 0\n");
+    out.push_str("    fi\n");
+    out.push_str("    declare -F _zeroclaw >/dev/null && _zeroclaw \"$@\"\n");
+    out.push_str("}\n");
+    out.push_str("complete -F _zeroclaw_integrations -o bashdefault -o default zeroclaw\n");
+    out
+}
+
+fn zsh(entries: &[&IntegrationEntry]) -> String {
+    let mut out =
+        String::from("_zeroclaw_integrations() {\n    local -a integrations\n    integrations=(\n");
+    for entry in entries {
+        let pair = format!("{}:{}", entry.name.replace(':', r"\:"), entry.description);
+        let _ = writeln!(out, "        {}", single_quote(&pair));
+    }
+    out.push_str("    )\n    _describe 'integration' integrations\n}\n\n");
+    out.push_str("_zeroclaw_with_integrations() {\n");
+    out.push_str(
+        "    if (( CURRENT == 4 )) && [[ $words[2] == integrations && $words[3] == info ]]; then\n",
+    );
+    out.push_str("        _zeroclaw_integrations\n");
+    out.push_str("    elif (( $+functions[_zeroclaw] )); then\n");
+    out.push_str("        _zeroclaw \"$@\"\n");
+    out.push_str("    fi\n}\n\n");
+    out.push_str("compdef _zeroclaw_with_integrations zeroclaw\n");
+    out
+}
+
+fn fish(entries: &[&IntegrationEntry]) -> String {
+    let mut out = String::new();
+    for entry in entries {
+        let _ = writeln!(
+            out,
+            "complete -c zeroclaw -n '__fish_seen_subcommand_from integrations; and __fish_seen_subcommand_from info' -f -a {} -d {}",
+            // Fish splits `-a` on whitespace, so spaces inside a name are escaped
+            fish_quote(entry.name).replace(' ', r"\ "),
+            fish_quote(entry.description)
+        );
+    }
+    out
+}
+
+/// Fish single-quoted string; only `\` and `'` need escaping.
+fn fish_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\\', r"\\").replace('\'', r"\'"))
+}
+
+/// (De)serialize a [`Shell`] through its `Display`/`FromStr` names so
+/// `IntegrationCommands` keeps its serde derives.
+pub mod shell_serde {
+    use clap_complete::Shell;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// serde's `with` passes the field as `&Shell`; accept any `Deref` to
+    /// it so the `Copy` value is taken rather than borrowed.
+    pub fn serialize<S: Serializer>(
+        shell: impl std::ops::Deref<Target = Shell>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let shell: Shell = *shell;
+        serializer.collect_str(&shell)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Shell, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::registry;
+
+    #[test]
+    fn bash_script_lists_every_registry_name() {
+        let entries = registry::all_integrations();
+        let out = script(Shell::Bash, &entries).unwrap();
+        assert!(out.starts_with("_zeroclaw_integrations() {"));
+        for entry in &entries {
+            assert!(
+                out.lines().any(|line| line.contains(entry.name)),
+                "{}",
+                entry.name
+            );
+        }
+    }
+
+    #[test]
+    fn zsh_script_pairs_names_with_descriptions() {
+        let entries = registry::all_integrations();
+        let out = script(Shell::Zsh, &entries).unwrap();
+        let teams = entries
+            .iter()
+            .find(|e| e.name == "Microsoft Teams")
+            .unwrap();
+        assert!(out.contains(&format!("'Microsoft Teams:{}'", teams.description)));
+        assert!(out.contains("_describe 'integration' integrations"));
+    }
+
+    #[test]
+    fn fish_script_escapes_spaces_in_names() {
+        let entries = registry::all_integrations();
+        let out = script(Shell::Fish, &entries).unwrap();
+        assert_eq!(out.lines().count(), entries.len());
+        assert!(out.contains(
+            "complete -c zeroclaw -n '__fish_seen_subcommand_from integrations; and __fish_seen_subcommand_from info' -f -a 'Microsoft\\ Teams'"
+        ));
+    }
+
+    #[test]
+    fn unsupported_shell_is_rejected() {
+        let entries = registry::all_integrations();
+        assert!(matches!(
+            script(Shell::PowerShell, &entries),
+            Err(IntegrationError::InvalidOption {
+                option: "shell",
+                ..
+            })
+        ));
+    }
+}
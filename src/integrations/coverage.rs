@@ -0,0 +1,98 @@
+use std::path::{Path, PathBuf};
+
+/// File name of the `cargo llvm-cov --json` report
+pub const DEFAULT_REPORT: &str = "coverage.json";
+
+/// [`DEFAULT_REPORT`] at the root of the crate this binary was built from,
+/// where `cargo llvm-cov --json --output-path coverage.json` leaves it
+pub fn default_report_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_REPORT)
+}
+
+/// Line coverage (0–100) of `module_path` (e.g. `channels::telegram`) from
+/// [`default_report_path`], or `None` if the report is missing or doesn't
+/// cover the module.
+pub fn module_coverage(module_path: &str) -> Option<f64> {
+    module_coverage_in(&default_report_path(), module_path)
+}
+
+/// Same as [`module_coverage`], reading the report at `report`.
+///
+/// Lines are summed over `src/<module>.rs` and everything under
+/// `src/<module>/`, so a directory module counts all of its files.
+pub fn module_coverage_in(report: &Path, module_path: &str) -> Option<f64> {
+    let raw = std::fs::read_to_string(report).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&raw).ok()?;
+
+    let relative = module_path.replace("::", "/");
+    let file_suffix = format!("src/{relative}.rs");
+    let dir_prefix = format!("src/{relative}/");
+
+    let (mut count, mut covered) = (0u64, 0u64);
+    for file in json["data"]
+        .as_array()?
+        .iter()
+        .filter_map(|export| export["files"].as_array())
+        .flatten()
+    {
+        let Some(filename) = file["filename"].as_str() else {
+            continue;
+        };
+        let filename = filename.replace('\\', "/");
+        if !filename.ends_with(&file_suffix) && !filename.contains(&dir_prefix) {
+            continue;
+        }
+        let lines = &file["summary"]["lines"];
+        count += lines["count"].as_u64().unwrap_or(0);
+        covered += lines["covered"].as_u64().unwrap_or(0);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    (count > 0).then(|| covered as f64 * 100.0 / count as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(filename: &str, count: u64, covered: u64) -> serde_json::Value {
+        serde_json::json!({
+            "filename": filename,
+            "summary": { "lines": { "count": count, "covered": covered } }
+        })
+    }
+
+    #[test]
+    fn module_coverage_in_sums_matching_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let report = dir.path().join("coverage.json");
+        let json = serde_json::json!({
+            "type": "llvm.coverage.json.export",
+            "data": [{
+                "files": [
+                    file("/work/zeroclaw/src/channels/telegram.rs", 1000, 873),
+                    file("/work/zeroclaw/src/channels/telegram_extra.rs", 10, 0),
+                    file("/work/zeroclaw/src/providers/ollama/mod.rs", 30, 30),
+                    file("/work/zeroclaw/src/providers/ollama/stream.rs", 10, 0),
+                ]
+            }]
+        });
+        std::fs::write(&report, json.to_string()).unwrap();
+
+        let telegram = module_coverage_in(&report, "channels::telegram").unwrap();
+        assert!((telegram - 87.3).abs() < 1e-9);
+        let ollama = module_coverage_in(&report, "providers::ollama").unwrap();
+        assert!((ollama - 75.0).abs() < 1e-9);
+        assert_eq!(module_coverage_in(&report, "channels::discord"), None);
+    }
+
+    #[test]
+    fn module_coverage_in_missing_or_malformed_report_is_none() {
+        let dir = tempfile::tempdir().unwrap();
+        let report = dir.path().join("coverage.json");
+        assert_eq!(module_coverage_in(&report, "channels::telegram"), None);
+
+        std::fs::write(&report, "not json").unwrap();
+        assert_eq!(module_coverage_in(&report, "channels::telegram"), None);
+    }
+}
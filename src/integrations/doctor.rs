@@ -0,0 +1,363 @@
+use super::state::StateStore;
+use super::tls;
+use super::{resolve_status, HealthCheckFuture, IntegrationEntry};
+use crate::config::Config;
+use futures_util::future::join_all;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// Default per-integration timeout for `integrations doctor`
+pub const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+/// Outcome of one integration's preflight check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckResult {
+    Passed,
+    Failed(String),
+    /// The entry has no `health_check_fn`
+    Skipped,
+}
+
+/// One row of the `integrations doctor` table
+#[derive(Debug, Clone)]
+pub struct CheckReport {
+    pub name: &'static str,
+    pub result: CheckResult,
+    pub latency: Duration,
+    /// Time left on the TLS certificate of an HTTPS `api_base_url`, if probed
+    pub cert_expires_in: Option<Duration>,
+}
+
+impl CheckReport {
+    pub fn failed(&self) -> bool {
+        matches!(self.result, CheckResult::Failed(_))
+    }
+}
+
+/// Run the preflight check of every configured entry (`Active`, `Degraded`
+/// or `AuthExpired`) concurrently, giving each at most `timeout`. Entries
+/// that pass and talk to an HTTPS base URL also get their certificate expiry
+/// probed, within their own `timeout`. Reports keep the order of `entries`.
+pub async fn run(
+    entries: &[&IntegrationEntry],
+    config: &Config,
+    timeout: Duration,
+) -> Vec<CheckReport> {
+    join_all(
+        entries
+            .iter()
+            .filter(|entry| resolve_status(entry, config).is_configured())
+            .map(|entry| check_entry(entry, config, timeout)),
+    )
+    .await
+}
+
+/// Health check and, if it passed, TLS probe for one entry.
+async fn check_entry(entry: &IntegrationEntry, config: &Config, timeout: Duration) -> CheckReport {
+    let Some(check) = entry.health_check_fn else {
+        return CheckReport {
+            name: entry.name,
+            result: CheckResult::Skipped,
+            latency: Duration::ZERO,
+            cert_expires_in: None,
+        };
+    };
+
+    let started = Instant::now();
+    let result = match tokio::time::timeout(timeout, check(config)).await {
+        Ok(Ok(())) => CheckResult::Passed,
+        Ok(Err(e)) => CheckResult::Failed(crate::providers::sanitize_api_error(&e.to_string())),
+        Err(_) => CheckResult::Failed(format!("timed out after {timeout:?}")),
+    };
+    let latency = started.elapsed();
+    let cert_expires_in = match base_url(entry.name, config) {
+        Some(url) if result == CheckResult::Passed => cert_expiry(entry.name, &url, timeout).await,
+        _ => None,
+    };
+    CheckReport {
+        name: entry.name,
+        result,
+        latency,
+        cert_expires_in,
+    }
+}
+
+/// Base URL `integrations doctor` uses for integration `name`, shared by its
+/// health check and TLS probe so both reach the same endpoint: Telegram's
+/// `channels_config.telegram.base_url`, Ollama's provider `api_url` unless
+/// `[integrations.ollama]` overrides it, else
+/// [`Config::integration_base_url`].
+fn base_url(name: &str, config: &Config) -> Option<String> {
+    match name {
+        "Telegram" => config
+            .channels_config
+            .telegram
+            .as_ref()
+            .and_then(|telegram| telegram.base_url.as_deref())
+            .map(|url| url.trim_end_matches('/').to_string())
+            .or_else(|| config.integration_base_url(name)),
+        "Ollama" => match (config.integrations.base_url(name), &config.api_url) {
+            (None, Some(api_url)) => Some(api_url.trim_end_matches('/').to_string()),
+            _ => config.integration_base_url(name),
+        },
+        _ => config.integration_base_url(name),
+    }
+}
+
+/// Certificate lifetime left at `url`. Probe failures are dropped: the
+/// health check already covers reachability.
+async fn cert_expiry(name: &str, url: &str, timeout: Duration) -> Option<Duration> {
+    match tokio::time::timeout(timeout, tls::check_cert_expiry(url)).await {
+        Ok(Ok(remaining)) => remaining,
+        Ok(Err(e)) => {
+            tracing::debug!("TLS probe of {name} failed: {e}");
+            None
+        }
+        Err(_) => None,
+    }
+}
+
+/// Append the duration of every check that ran to the `health_checks` history.
+pub fn record(store: &StateStore, reports: &[CheckReport]) -> anyhow::Result<()> {
+    for report in reports {
+        if report.result != CheckResult::Skipped {
+            store.record_check(report.name, report.latency, !report.failed())?;
+        }
+    }
+    Ok(())
+}
+
+/// Render reports as a name / result / latency / error table, with a
+/// `⚠ TLS cert expires in N days` line under rows whose certificate is close
+/// to expiry.
+pub fn render_table(reports: &[CheckReport]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "  {:<20} {:<6} {:>9}  Error",
+        "Integration", "Result", "Latency"
+    );
+    for report in reports {
+        let (icon, latency, error) = match &report.result {
+            CheckResult::Passed => ("✅", format!("{} ms", report.latency.as_millis()), ""),
+            CheckResult::Failed(reason) => (
+                "❌",
+                format!("{} ms", report.latency.as_millis()),
+                reason.as_str(),
+            ),
+            CheckResult::Skipped => ("⚪", "—".to_string(), "no preflight check"),
+        };
+        let _ = writeln!(
+            out,
+            "  {:<20} {:<6} {latency:>9}  {error}",
+            report.name, icon
+        );
+        if let Some(warning) = report.cert_expires_in.and_then(tls::expiry_warning) {
+            let _ = writeln!(out, "  {:<20} {warning}", "");
+        }
+    }
+    out
+}
+
+/// GET `url` and fail unless the response is a 2xx.
+async fn expect_success(url: String, service_key: &'static str) -> anyhow::Result<()> {
+    crate::config::build_runtime_proxy_client(service_key)
+        .get(url)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        // The URL can carry a bot token; keep it out of the report.
+        .map_err(reqwest::Error::without_url)?;
+    Ok(())
+}
+
+/// Telegram: `getMe` with the configured bot token.
+pub(super) fn telegram(config: &Config) -> HealthCheckFuture {
+    let telegram = config.channels_config.telegram.clone();
+    let base = base_url("Telegram", config);
+    Box::pin(async move {
+        let telegram =
+            telegram.ok_or_else(|| anyhow::anyhow!("channels_config.telegram is not set"))?;
+        let base = base.ok_or_else(|| anyhow::anyhow!("no Telegram API base URL"))?;
+        expect_success(
+            format!("{base}/bot{}/getMe", telegram.bot_token),
+            "integrations.doctor.telegram",
+        )
+        .await
+    })
+}
+
+/// Ollama: list local models via `/api/tags`. An `[integrations.ollama]`
+/// override wins over the provider-wide `api_url`.
+pub(super) fn ollama(config: &Config) -> HealthCheckFuture {
+    let base = base_url("Ollama", config);
+    Box::pin(async move {
+        let base = base.ok_or_else(|| anyhow::anyhow!("no Ollama API base URL"))?;
+        expect_success(format!("{base}/api/tags"), "integrations.doctor.ollama").await
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::{
+        HealthCheckFn, IntegrationCategory, IntegrationStatus, PricingModel, StatusFn,
+    };
+    use std::sync::LazyLock;
+
+    fn active_entry(
+        name: &'static str,
+        health_check_fn: Option<HealthCheckFn>,
+    ) -> IntegrationEntry {
+        let builder = IntegrationEntry::builder()
+            .name(name)
+            .description("Test entry")
+            .category(IntegrationCategory::ToolsAutomation)
+            .license("MIT")
+            .pricing_model(PricingModel::OpenSource)
+            .status_fn(|_| IntegrationStatus::Active);
+        match health_check_fn {
+            Some(check) => builder.health_check_fn(check),
+            None => builder,
+        }
+        .build()
+    }
+
+    fn with_status(
+        mut entry: IntegrationEntry,
+        status_fn: fn(&Config) -> IntegrationStatus,
+    ) -> IntegrationEntry {
+        entry.status_fn = StatusFn::Static(status_fn);
+        entry
+    }
+
+    #[tokio::test]
+    async fn run_reports_pass_fail_timeout_and_skip() {
+        let config = Config::default();
+        let inactive = with_status(active_entry("Inactive", None), |_| {
+            IntegrationStatus::Available
+        });
+        let entries = [
+            active_entry("Ok", Some(|_| Box::pin(async { Ok(()) }))),
+            active_entry(
+                "Broken",
+                Some(|_| Box::pin(async { anyhow::bail!("bad token") })),
+            ),
+            active_entry(
+                "Slow",
+                Some(|_| {
+                    Box::pin(async {
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                        Ok(())
+                    })
+                }),
+            ),
+            active_entry("Unchecked", None),
+            inactive,
+            with_status(
+                active_entry("Degraded", Some(|_| Box::pin(async { Ok(()) }))),
+                |_| IntegrationStatus::Degraded("slow".into()),
+            ),
+            with_status(
+                active_entry("Expired", Some(|_| Box::pin(async { Ok(()) }))),
+                |_| IntegrationStatus::AuthExpired { since: None },
+            ),
+        ];
+
+        let entries: Vec<_> = entries.iter().collect();
+        let reports = run(&entries, &config, Duration::from_millis(50)).await;
+        let results: Vec<_> = reports.iter().map(|r| (r.name, r.result.clone())).collect();
+        assert_eq!(
+            results,
+            [
+                ("Ok", CheckResult::Passed),
+                ("Broken", CheckResult::Failed("bad token".into())),
+                ("Slow", CheckResult::Failed("timed out after 50ms".into())),
+                ("Unchecked", CheckResult::Skipped),
+                ("Degraded", CheckResult::Passed),
+                ("Expired", CheckResult::Passed),
+            ]
+        );
+        assert_eq!(reports.iter().filter(|r| r.failed()).count(), 2);
+    }
+
+    #[tokio::test]
+    async fn run_checks_entries_concurrently() {
+        // Each check waits for the other, so running them one at a time
+        // would time both out.
+        static BARRIER: LazyLock<tokio::sync::Barrier> =
+            LazyLock::new(|| tokio::sync::Barrier::new(2));
+        let wait: HealthCheckFn = |_| {
+            Box::pin(async {
+                BARRIER.wait().await;
+                Ok(())
+            })
+        };
+        let entries = [
+            active_entry("First", Some(wait)),
+            active_entry("Second", Some(wait)),
+        ];
+
+        let entries: Vec<_> = entries.iter().collect();
+        let reports = run(&entries, &Config::default(), Duration::from_secs(5)).await;
+        assert!(reports.iter().all(|r| r.result == CheckResult::Passed));
+    }
+
+    #[test]
+    fn base_url_prefers_the_telegram_channel_setting() {
+        let mut config: Config = toml::from_str(
+            r#"
+default_temperature = 0.7
+
+[integrations.telegram]
+base_url = "https://override.example"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            base_url("Telegram", &config).as_deref(),
+            Some("https://override.example")
+        );
+
+        config.channels_config.telegram = Some(crate::config::TelegramConfig {
+            bot_token: "123:ABC".into(),
+            allowed_users: vec![],
+            stream_mode: crate::config::StreamMode::default(),
+            draft_update_interval_ms: 1000,
+            interrupt_on_new_message: false,
+            mention_only: false,
+            group_reply: None,
+            base_url: Some("https://tg.example/".into()),
+        });
+        assert_eq!(
+            base_url("Telegram", &config).as_deref(),
+            Some("https://tg.example")
+        );
+    }
+
+    #[test]
+    fn render_table_shows_icon_latency_and_error() {
+        let out = render_table(&[
+            CheckReport {
+                name: "Telegram",
+                result: CheckResult::Passed,
+                latency: Duration::from_millis(182),
+                cert_expires_in: Some(Duration::from_secs(14 * 24 * 60 * 60 + 60)),
+            },
+            CheckReport {
+                name: "Ollama",
+                result: CheckResult::Failed("connection refused".into()),
+                latency: Duration::from_millis(3),
+                cert_expires_in: None,
+            },
+        ]);
+        let lines: Vec<_> = out.lines().collect();
+        assert!(lines[0].contains("Integration") && lines[0].contains("Latency"));
+        assert!(
+            lines[1].contains("Telegram") && lines[1].contains('✅') && lines[1].contains("182 ms")
+        );
+        assert_eq!(lines[2].trim(), "⚠ TLS cert expires in 14 days");
+        assert!(lines[3].contains('❌') && lines[3].ends_with("connection refused"));
+        assert_eq!(lines.len(), 4);
+    }
+}
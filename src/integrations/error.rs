@@ -0,0 +1,193 @@
+/// Errors from integration lookup, filter parsing and the `integrations` CLI
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IntegrationError {
+    #[error("Unknown integration '{name}'. {}", unknown_integration_hint(.suggestions))]
+    UnknownIntegration {
+        name: String,
+        /// Closest registry names, best first
+        suggestions: Vec<String>,
+    },
+    #[error("Unknown category: '{input}'. Valid: {}", .valid.join(", "))]
+    InvalidCategory { input: String, valid: Vec<String> },
+    #[error("Unknown status: '{input}'. Valid: {}", .valid.join(", "))]
+    InvalidStatus { input: String, valid: Vec<String> },
+    /// Any other unrecognised option value (maturity, pricing, output format, ...)
+    #[error("Unknown {option}: '{input}'. Valid: {}", .valid.join(", "))]
+    InvalidOption {
+        option: &'static str,
+        input: String,
+        valid: Vec<String>,
+    },
+    #[error("No saved search preset named '{0}'")]
+    UnknownPreset(String),
+    #[error("Missing search query (or pass --load <name>)")]
+    MissingQuery,
+    #[error("expected at least {min} results, got {found}")]
+    TooFewResults { min: usize, found: usize },
+    #[error("{failed} of {total} integration checks failed")]
+    ChecksFailed { failed: usize, total: usize },
+    #[error("{0} has no live connectivity test yet")]
+    NoConnectionTest(String),
+    #[error("{name} connectivity test failed: {reason}")]
+    ConnectionTestFailed { name: String, reason: String },
+    #[error("on-success command `{command}` failed{}", .code.map(|c| format!(" with exit code {c}")).unwrap_or_default())]
+    OnSuccessCommandFailed { command: String, code: Option<i32> },
+    #[error("An integration named '{0}' is already registered")]
+    DuplicateIntegration(String),
+    #[error("{name} is missing required config keys: {}", .missing.join(", "))]
+    MissingConfigKeys { name: String, missing: Vec<String> },
+}
+
+const CATEGORY_NAMES: &[&str] = &[
+    "chat",
+    "ai",
+    "productivity",
+    "music",
+    "smart-home",
+    "tools",
+    "media",
+    "social",
+    "platform",
+];
+
+const STATUS_NAMES: &[&str] = &[
+    "active",
+    "available",
+    "coming-soon",
+    "degraded",
+    "auth-expired",
+];
+
+fn owned(values: &[&str]) -> Vec<String> {
+    values.iter().map(ToString::to_string).collect()
+}
+
+fn unknown_integration_hint(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => "Check README for supported integrations or run `zeroclaw onboard --interactive` to configure channels/providers.".to_string(),
+        [only] => format!("Did you mean '{only}'?"),
+        many => format!(
+            "Did you mean one of: {}?",
+            many.iter()
+                .map(|s| format!("'{s}'"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+impl IntegrationError {
+    /// `UnknownIntegration` with suggestions drawn from `candidates`.
+    pub fn unknown_integration<'a>(
+        name: &str,
+        candidates: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        Self::UnknownIntegration {
+            name: name.to_string(),
+            suggestions: closest_names(name, candidates),
+        }
+    }
+
+    pub fn invalid_category(input: &str) -> Self {
+        Self::InvalidCategory {
+            input: input.to_string(),
+            valid: owned(CATEGORY_NAMES),
+        }
+    }
+
+    pub fn invalid_status(input: &str) -> Self {
+        Self::InvalidStatus {
+            input: input.to_string(),
+            valid: owned(STATUS_NAMES),
+        }
+    }
+
+    pub fn invalid_option(option: &'static str, input: &str, valid: &[&str]) -> Self {
+        Self::InvalidOption {
+            option,
+            input: input.to_string(),
+            valid: owned(valid),
+        }
+    }
+
+    /// Process exit code the CLI should use instead of the default 1: the
+    /// on-success command's own code, when it exited with one.
+    pub fn exit_code(&self) -> Option<i32> {
+        match self {
+            Self::OnSuccessCommandFailed { code, .. } => *code,
+            _ => None,
+        }
+    }
+}
+
+/// Case-insensitive edit distance between `a` and `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            row[j + 1] = substitution.min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+/// Candidates tied for the smallest edit distance to `name`, if that
+/// distance is small enough to be a plausible typo (a third of the name,
+/// at least 2).
+fn closest_names<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let max_distance = (name.chars().count() / 3).max(2);
+    let scored: Vec<_> = candidates
+        .into_iter()
+        .map(|candidate| (levenshtein(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    let Some(best) = scored.iter().map(|(distance, _)| *distance).min() else {
+        return Vec::new();
+    };
+    scored
+        .into_iter()
+        .filter(|(distance, _)| *distance == best)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levenshtein_counts_edits_ignoring_case() {
+        assert_eq!(levenshtein("telgram", "Telegram"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("Shell", "shell"), 0);
+    }
+
+    #[test]
+    fn unknown_integration_suggests_closest_name() {
+        let err =
+            IntegrationError::unknown_integration("telgram", ["Telegram", "Discord", "Slack"]);
+        assert_eq!(
+            err.to_string(),
+            "Unknown integration 'telgram'. Did you mean 'Telegram'?"
+        );
+    }
+
+    #[test]
+    fn unknown_integration_without_close_match_points_to_readme() {
+        let err = IntegrationError::unknown_integration("zzzzzz", ["Telegram"]);
+        assert_eq!(
+            err,
+            IntegrationError::UnknownIntegration {
+                name: "zzzzzz".into(),
+                suggestions: Vec::new(),
+            }
+        );
+        assert!(err.to_string().contains("Check README"));
+    }
+}
@@ -0,0 +1,173 @@
+use super::doctor::{CheckReport, CheckResult};
+use super::{IntegrationEntry, IntegrationStatus};
+use chrono::{DateTime, Utc};
+use std::fmt::Write;
+
+/// CSS modifier for a status, used as `zc-status--<slug>` so UIs can theme
+/// the icon instead of relying on emoji.
+fn status_slug(status: &IntegrationStatus) -> &'static str {
+    match status {
+        IntegrationStatus::Active => "active",
+        IntegrationStatus::Available => "available",
+        IntegrationStatus::ComingSoon => "coming-soon",
+        IntegrationStatus::Degraded(_) => "degraded",
+        IntegrationStatus::AuthExpired { .. } => "auth-expired",
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Render the `integrations info` block as a single `<div>` for embedding
+/// in web UIs.
+pub fn render_entry(entry: &IntegrationEntry, status: IntegrationStatus) -> String {
+    let slug = status_slug(&status);
+    format!(
+        concat!(
+            "<div class=\"zc-integration zc-status--{slug}\">\n",
+            "  <span class=\"zc-status-icon zc-status--{slug}\"></span>\n",
+            "  <span class=\"zc-name\">{name}</span>\n",
+            "  <span class=\"zc-description\">{description}</span>\n",
+            "  <span class=\"zc-category\">{category}</span>\n",
+            "  <span class=\"zc-status\">{status}</span>\n",
+            "  <span class=\"zc-license\">{license}</span>\n",
+            "</div>\n",
+        ),
+        slug = slug,
+        name = escape(entry.name),
+        description = escape(entry.description),
+        category = escape(entry.category.label()),
+        status = escape(&status.to_string()),
+        license = escape(entry.license),
+    )
+}
+
+/// Styles for [`render_check_report`], inlined so the report is a single
+/// self-contained file.
+const CHECK_REPORT_STYLE: &str = "\
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 2rem; color: #1d1c1d; }
+h1 { font-size: 1.4rem; margin-bottom: 0.2rem; }
+.zc-generated { color: #616061; margin-top: 0; }
+table { border-collapse: collapse; min-width: 40rem; }
+th, td { padding: 0.4rem 0.8rem; text-align: left; border-bottom: 1px solid #ddd; }
+th { background: #f4f4f4; }
+td.zc-latency { text-align: right; font-variant-numeric: tabular-nums; }
+tr.zc-check--passed { background: #e3f6ec; }
+tr.zc-check--failed { background: #fbe4ea; }
+tr.zc-check--skipped { color: #616061; }
+";
+
+/// Render `integrations doctor` results as a standalone HTML page: a
+/// timestamp header and one table row per check, green when it passed and
+/// red when it failed.
+pub fn render_check_report(reports: &[CheckReport], generated_at: DateTime<Utc>) -> String {
+    let failed = reports.iter().filter(|r| r.failed()).count();
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        concat!(
+            "<!DOCTYPE html>\n",
+            "<html lang=\"en\">\n",
+            "<head>\n",
+            "<meta charset=\"utf-8\">\n",
+            "<title>ZeroClaw integration checks</title>\n",
+            "<style>\n{style}</style>\n",
+            "</head>\n",
+            "<body>\n",
+            "<h1>ZeroClaw integration checks: {passed} of {total} passed</h1>\n",
+            "<p class=\"zc-generated\">Generated {timestamp}</p>\n",
+            "<table>\n",
+            "<tr><th>Integration</th><th>Result</th><th>Latency</th><th>Error</th></tr>\n",
+        ),
+        style = CHECK_REPORT_STYLE,
+        passed = reports.len() - failed,
+        total = reports.len(),
+        timestamp = generated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+    );
+    for report in reports {
+        let (slug, result, latency, error) = match &report.result {
+            CheckResult::Passed => (
+                "passed",
+                "Passed",
+                format!("{} ms", report.latency.as_millis()),
+                "",
+            ),
+            CheckResult::Failed(reason) => (
+                "failed",
+                "Failed",
+                format!("{} ms", report.latency.as_millis()),
+                reason.as_str(),
+            ),
+            CheckResult::Skipped => ("skipped", "Skipped", "—".to_string(), "no preflight check"),
+        };
+        let _ = writeln!(
+            out,
+            "<tr class=\"zc-check--{slug}\"><td>{}</td><td>{result}</td><td class=\"zc-latency\">{latency}</td><td>{}</td></tr>",
+            escape(report.name),
+            escape(error),
+        );
+    }
+    out.push_str("</table>\n</body>\n</html>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::registry;
+
+    #[test]
+    fn render_entry_wraps_fields_in_a_single_div() {
+        let entries = registry::all_integrations();
+        let media = entries
+            .iter()
+            .find(|e| e.category.label() == "Media & Creative")
+            .unwrap();
+
+        let out = render_entry(media, IntegrationStatus::Degraded("<token> expired".into()));
+        let trimmed = out.trim();
+        assert!(trimmed.starts_with("<div class=\"zc-integration zc-status--degraded\">"));
+        assert!(trimmed.ends_with("</div>"));
+        assert_eq!(out.matches("<div").count(), 1);
+        assert_eq!(out.matches("<span").count(), out.matches("</span>").count());
+        assert!(out.contains("Media &amp; Creative"));
+        assert!(out.contains("Degraded (&lt;token&gt; expired)"));
+        assert!(!out.contains("⚠"));
+    }
+
+    #[test]
+    fn render_check_report_has_one_coloured_row_per_result() {
+        use chrono::TimeZone;
+        use std::time::Duration;
+
+        let report = |name, result| CheckReport {
+            name,
+            result,
+            latency: Duration::from_millis(12),
+            cert_expires_in: None,
+        };
+        let out = render_check_report(
+            &[
+                report("Telegram", CheckResult::Passed),
+                report("Ollama", CheckResult::Failed("<refused>".into())),
+                report("Cron", CheckResult::Skipped),
+            ],
+            Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap(),
+        );
+
+        assert!(out.contains("<table>") && out.contains("</table>"));
+        // Header row plus one row per result
+        assert_eq!(out.matches("<tr").count(), 4);
+        assert!(out.contains("<tr class=\"zc-check--passed\"><td>Telegram</td>"));
+        assert!(out.contains("<tr class=\"zc-check--failed\"><td>Ollama</td>"));
+        assert!(out.contains("&lt;refused&gt;"));
+        assert!(out.contains("Generated 2026-03-01 09:30:00 UTC"));
+        assert!(out.contains("2 of 3 passed"));
+        assert!(!out.contains("<link") && !out.contains("<script"));
+    }
+}
@@ -0,0 +1,1796 @@
+use super::{
+    auth_expired_age, auth_type_filter_arg, category_filter_arg, category_matches,
+    category_order_arg, config_key_is_set, format_age, has_tag, is_open_source_license,
+    maturity_filter_arg, message_type_filter_arg, parse_license_filter, pricing_filter_arg,
+    prometheus, registry, resolve_status, status_filter_arg, status_group_label, status_icon,
+    status_matches, supports_current_platform, write_atomically, IntegrationCategory,
+    IntegrationEntry, IntegrationError, IntegrationStatus, IntegrationSummary, Maturity,
+};
+use crate::config::Config;
+use crate::IntegrationListArgs;
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Badge shown after an entry's name in `integrations list`; empty for stable.
+fn maturity_badge(maturity: Maturity) -> &'static str {
+    match maturity {
+        Maturity::Stable => "",
+        Maturity::Beta => "[beta]",
+        Maturity::Experimental => "[experimental]",
+    }
+}
+
+/// Environment variable naming the output format `integrations list` and
+/// `integrations search` use when no format flag is given
+pub(super) const OUTPUT_FORMAT_ENV: &str = "ZEROCLAW_OUTPUT_FORMAT";
+
+/// How `integrations list` renders its results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) enum OutputFormat {
+    /// Grouped, styled table (default)
+    #[default]
+    Text,
+    /// Bash array assignment for `source <(...)`
+    ShellArray,
+    /// Prometheus text exposition, one gauge sample per integration
+    Prometheus,
+    /// `{"integrations": [IntegrationSummary...], "registry_checksum": ...}`
+    Json,
+    /// `name\tcategory\tstatus\tdescription` rows for `cut`/`awk`
+    Tsv,
+    /// One unstyled `name icon status` line per entry, no headers or footer
+    Compact,
+    /// The text table plus each entry's category and set config keys
+    Verbose,
+}
+
+fn parse_output_format(input: &str) -> Option<OutputFormat> {
+    match input.to_lowercase().as_str() {
+        "text" => Some(OutputFormat::Text),
+        "shell-array" | "shell" | "bash" => Some(OutputFormat::ShellArray),
+        "prometheus" | "prom" => Some(OutputFormat::Prometheus),
+        "json" => Some(OutputFormat::Json),
+        "tsv" => Some(OutputFormat::Tsv),
+        "compact" => Some(OutputFormat::Compact),
+        "verbose" => Some(OutputFormat::Verbose),
+        _ => None,
+    }
+}
+
+/// `integrations search` format from `ZEROCLAW_OUTPUT_FORMAT`. Search only
+/// renders text, json, compact and verbose; anything else falls back to text.
+pub(super) fn search_output_format(env: Option<&str>) -> OutputFormat {
+    env.and_then(parse_output_format)
+        .filter(|format| {
+            matches!(
+                format,
+                OutputFormat::Text
+                    | OutputFormat::Json
+                    | OutputFormat::Compact
+                    | OutputFormat::Verbose
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// Spacing of the text `integrations list` output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum RenderMode {
+    /// Blank line between sections (default)
+    #[default]
+    Spacious,
+    /// No blank lines, for long lists
+    Compact,
+}
+
+impl RenderMode {
+    fn blank_line(self, out: &mut String) {
+        if self == Self::Spacious {
+            out.push('\n');
+        }
+    }
+}
+
+/// Filters and rendering options for `integrations list`
+#[derive(Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct ListOptions<'a> {
+    category: Option<&'a str>,
+    status: Option<&'a str>,
+    license: Option<&'a str>,
+    maturity: Option<&'a str>,
+    tag: Option<&'a str>,
+    pricing: Option<&'a str>,
+    /// `Some(true)` for `--self-hosted`, `Some(false)` for `--no-self-hosted`
+    self_hosted: Option<bool>,
+    auth_type: Option<&'a str>,
+    data_residency: Option<&'a str>,
+    supports: Option<&'a str>,
+    output: Option<&'a str>,
+    headers: bool,
+    dry_run: bool,
+    debug: bool,
+    output_file: Option<&'a Path>,
+    max_entries: Option<usize>,
+    group_by: Option<&'a str>,
+    category_order: Option<&'a str>,
+    sort_by: Option<&'a str>,
+    no_group: bool,
+    /// Leave the status icon off each row
+    no_status: bool,
+    all_platforms: bool,
+    summary: bool,
+    category_only: bool,
+    render_mode: RenderMode,
+}
+
+impl<'a> ListOptions<'a> {
+    /// Options for `args`. `env_output` ($ZEROCLAW_OUTPUT_FORMAT) applies when
+    /// no format flag is given; `env_debug` is $ZEROCLAW_DEBUG.
+    fn from_args(
+        args: &'a IntegrationListArgs,
+        env_output: Option<&'a str>,
+        env_debug: Option<&str>,
+    ) -> Self {
+        Self {
+            category: args.category.as_deref(),
+            status: args.status.as_deref(),
+            license: args.license.as_deref(),
+            maturity: args.filter_maturity.as_deref(),
+            tag: args.tag.as_deref(),
+            pricing: args.pricing.as_deref(),
+            self_hosted: match (args.self_hosted, args.no_self_hosted) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            },
+            auth_type: args.auth_type.as_deref(),
+            data_residency: args.data_residency.as_deref(),
+            supports: args.supports.as_deref(),
+            output: if args.json {
+                Some("json")
+            } else if args.verbose {
+                Some("verbose")
+            } else {
+                args.output.as_deref().or(env_output)
+            },
+            headers: args.headers,
+            dry_run: args.dry_run,
+            debug: debug_enabled(args.debug, env_debug),
+            output_file: args.output_file.as_deref(),
+            max_entries: args.max_entries,
+            group_by: args.group_by.as_deref(),
+            category_order: args.category_order.as_deref(),
+            sort_by: args.sort_by.as_deref(),
+            no_group: args.no_group,
+            no_status: args.no_status,
+            all_platforms: args.all_platforms,
+            summary: args.summary,
+            category_only: args.category_only,
+            render_mode: if args.compact {
+                RenderMode::Compact
+            } else {
+                RenderMode::Spacious
+            },
+        }
+    }
+}
+
+/// `integrations list`: print once, or keep re-rendering with `--live`.
+pub(super) async fn run(config: &Config, args: &IntegrationListArgs) -> Result<()> {
+    let env_output = std::env::var(OUTPUT_FORMAT_ENV).ok();
+    let env_debug = std::env::var("ZEROCLAW_DEBUG").ok();
+    let options = ListOptions::from_args(args, env_output.as_deref(), env_debug.as_deref());
+    if !args.live {
+        return print_list(config, &options);
+    }
+    Box::pin(live_loop(
+        config.clone(),
+        Duration::from_secs(args.interval.max(1)),
+        Config::reload,
+        |config| print_list(config, &options),
+    ))
+    .await
+}
+
+fn print_list(config: &Config, options: &ListOptions<'_>) -> Result<()> {
+    let rendered = render_list(config, options)?;
+    match options.output_file {
+        Some(path) => write_atomically(path, &console::strip_ansi_codes(&rendered)),
+        None => {
+            print!("{rendered}");
+            Ok(())
+        }
+    }
+}
+
+/// Render `integrations list` in the requested format.
+fn render_list(config: &Config, options: &ListOptions<'_>) -> Result<String> {
+    if options.category_only {
+        return Ok(render_category_labels());
+    }
+
+    let entries = registry::all_integrations();
+    let category_filter = options.category;
+    let status_filter = options.status;
+    let license_filter = options.license;
+
+    let cat_filter = category_filter_arg(category_filter)?;
+    let stat_filter = status_filter_arg(status_filter)?;
+    let maturity_filter = maturity_filter_arg(options.maturity)?;
+    let pricing_filter = pricing_filter_arg(options.pricing)?;
+    let auth_filter = auth_type_filter_arg(options.auth_type)?;
+    let supports_filter = message_type_filter_arg(options.supports)?;
+    let category_order = category_order_arg(options.category_order)?;
+    let sort_by = match options.sort_by {
+        // Registry order is already by category, then name.
+        None if options.no_group => SortBy::Name,
+        None => SortBy::Category,
+        Some(sort_by) => parse_sort_by(sort_by).ok_or_else(|| {
+            IntegrationError::invalid_option("sort order", sort_by, &["name", "category", "status"])
+        })?,
+    };
+
+    let open_source_filter = license_filter.map(parse_license_filter);
+    if let Some(None) = open_source_filter {
+        return Err(IntegrationError::invalid_option(
+            "license filter",
+            license_filter.unwrap_or_default(),
+            &["open-source", "proprietary"],
+        )
+        .into());
+    }
+    let open_source_filter = open_source_filter.flatten();
+
+    let output_format = match options.output {
+        None => OutputFormat::Text,
+        Some(output) => parse_output_format(output).ok_or_else(|| {
+            IntegrationError::invalid_option(
+                "output format",
+                output,
+                &[
+                    "text",
+                    "shell-array",
+                    "prometheus",
+                    "json",
+                    "tsv",
+                    "compact",
+                    "verbose",
+                ],
+            )
+        })?,
+    };
+
+    let group_by = match options.group_by {
+        None => GroupBy::Category,
+        Some(group_by) => parse_group_by(group_by).ok_or_else(|| {
+            IntegrationError::invalid_option(
+                "grouping",
+                group_by,
+                &["category", "status", "maturity"],
+            )
+        })?,
+    };
+
+    let mut matches: Vec<_> = entries
+        .iter()
+        .copied()
+        .filter(|e| category_matches(&cat_filter, e.category))
+        .filter(|e| {
+            open_source_filter
+                .is_none_or(|open_source| is_open_source_license(e.license) == open_source)
+        })
+        .filter(|e| maturity_filter.is_none_or(|m| e.maturity == m))
+        .filter(|e| pricing_filter.is_none_or(|p| e.pricing_model == p))
+        .filter(|e| options.self_hosted.is_none_or(|s| e.self_hosted == s))
+        .filter(|e| auth_filter.is_none_or(|a| e.authentication_type == a))
+        .filter(|e| supports_filter.is_none_or(|m| e.supported_message_types.contains(&m)))
+        .filter(|e| {
+            options
+                .data_residency
+                .is_none_or(|region| e.data_residency.eq_ignore_ascii_case(region.trim()))
+        })
+        .filter(|e| options.all_platforms || supports_current_platform(e))
+        .filter(|e| options.tag.is_none_or(|tag| has_tag(e, tag)))
+        .map(|e| (e, resolve_status(e, config)))
+        .filter(|(_, status)| {
+            stat_filter
+                .as_ref()
+                .is_none_or(|sf| status_matches(status, sf))
+        })
+        .collect();
+
+    sort_entries(&mut matches, sort_by, &category_order);
+
+    if options.summary {
+        let mut by_category: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for (entry, _) in &matches {
+            by_category.entry(entry.category).or_default().push(*entry);
+        }
+        return Ok(render_summary(&by_category, &category_order, config));
+    }
+
+    let mut groups = if options.no_group {
+        vec![("", matches)]
+    } else {
+        group_entries(matches, group_by, &category_order)
+    };
+    let matching = match options.max_entries {
+        Some(max) => truncate_groups(&mut groups, max),
+        None => groups
+            .iter()
+            .map(|(_, cat_entries)| cat_entries.len())
+            .sum(),
+    };
+
+    if options.dry_run {
+        let statuses: Vec<_> = groups
+            .iter()
+            .flat_map(|(_, cat_entries)| cat_entries.iter().map(|(_, status)| status))
+            .collect();
+        return Ok(format!("{}\n", dry_run_summary(&statuses)));
+    }
+
+    if output_format == OutputFormat::ShellArray {
+        let names: Vec<_> = groups
+            .iter()
+            .flat_map(|(_, cat_entries)| cat_entries.iter().map(|(entry, _)| entry.name))
+            .collect();
+        return Ok(render_shell_array(&names));
+    }
+
+    if output_format == OutputFormat::Json {
+        let matches: Vec<_> = groups
+            .into_iter()
+            .flat_map(|(_, cat_entries)| cat_entries)
+            .collect();
+        let json = serde_json::json!({
+            "integrations": summaries_json(&matches),
+            "registry_checksum": registry::checksum(),
+        });
+        return Ok(format!("{}\n", serde_json::to_string_pretty(&json)?));
+    }
+
+    if output_format == OutputFormat::Tsv {
+        let rows: Vec<_> = groups
+            .iter()
+            .flat_map(|(_, cat_entries)| cat_entries)
+            .collect();
+        return Ok(render_tsv(&rows, options.headers));
+    }
+
+    if output_format == OutputFormat::Prometheus {
+        let samples: Vec<_> = groups
+            .into_iter()
+            .flat_map(|(_, cat_entries)| cat_entries)
+            .collect();
+        return Ok(prometheus::render(&samples));
+    }
+
+    if output_format == OutputFormat::Compact {
+        let mut out = String::new();
+        for (entry, status) in groups.iter().flat_map(|(_, cat_entries)| cat_entries) {
+            let _ = writeln!(out, "{}", compact_row(entry, status));
+        }
+        return Ok(out);
+    }
+
+    let config_json = (output_format == OutputFormat::Verbose)
+        .then(|| serde_json::to_value(config).unwrap_or_default());
+    let row = |entry: &IntegrationEntry, status: &IntegrationStatus, inline_category: bool| {
+        let mut row = entry_row(entry, status, inline_category, !options.no_status);
+        if let Some(config_json) = &config_json {
+            row.push('\n');
+            row.push_str(&verbose_details(entry, config_json));
+        }
+        row
+    };
+
+    let mut out = String::new();
+    if let Some(line) = config_modified_line(&config.config_path, SystemTime::now()) {
+        options.render_mode.blank_line(&mut out);
+        let _ = writeln!(out, "  {}", console::style(line).dim());
+    }
+
+    let mut count = 0usize;
+    if options.no_group {
+        // Drain so the pinned and grouped sections below render nothing.
+        options.render_mode.blank_line(&mut out);
+        for (entry, status) in groups.drain(..).flat_map(|(_, cat_entries)| cat_entries) {
+            let _ = writeln!(out, "{}", row(entry, &status, true));
+            count += 1;
+        }
+    }
+    let pinned = take_pinned(&mut groups, &config.integrations.pinned.names);
+    if !pinned.is_empty() {
+        options.render_mode.blank_line(&mut out);
+        let _ = writeln!(
+            out,
+            "  {} ({})",
+            console::style("★ Pinned").bold().underlined(),
+            pinned.len()
+        );
+        for (entry, status) in &pinned {
+            let _ = writeln!(out, "{}", row(entry, status, false));
+            count += 1;
+        }
+    }
+    for (label, cat_entries) in &groups {
+        options.render_mode.blank_line(&mut out);
+        let (shown, coming_soon) = category_counts(cat_entries);
+        let title = match (group_by, cat_entries.first()) {
+            (GroupBy::Category, Some((entry, _))) => format!("{} {label}", entry.category.icon()),
+            _ => (*label).to_string(),
+        };
+        let mut heading = format!("  {} ({shown})", console::style(title).bold().underlined());
+        if coming_soon > 0 {
+            let _ = write!(
+                heading,
+                " {}",
+                console::style(format!("+{coming_soon} coming soon")).dim()
+            );
+        }
+        let _ = writeln!(out, "{heading}");
+        for (entry, status) in cat_entries {
+            let _ = writeln!(out, "{}", row(entry, status, false));
+            count += 1;
+        }
+    }
+
+    options.render_mode.blank_line(&mut out);
+    if count < matching {
+        let _ = writeln!(
+            out,
+            "  (truncated — showing first {count} of {matching} matching entries)"
+        );
+    }
+    let other_platforms = if options.all_platforms {
+        0
+    } else {
+        entries
+            .iter()
+            .filter(|e| !supports_current_platform(e))
+            .count()
+    };
+    let _ = writeln!(
+        out,
+        "  {}",
+        list_footer(count, entries.len(), other_platforms)
+    );
+    let score = health_score(config, &entries);
+    let _ = writeln!(out, "  Setup completeness: {score}%");
+    if score > 80 {
+        let _ = writeln!(
+            out,
+            "  {}",
+            console::style("Nice — almost everything available is set up!").green()
+        );
+    }
+    if options.debug {
+        let _ = writeln!(
+            out,
+            "  {}",
+            console::style(registry_source_line(entries.len())).dim()
+        );
+    }
+    options.render_mode.blank_line(&mut out);
+    Ok(out)
+}
+
+/// Drive `integrations list --live`: clear the terminal and render, then
+/// wait `interval`, reload config from disk, and render again until Ctrl-C
+/// or until `reload` or `render` fails.
+async fn live_loop<F>(
+    mut config: Config,
+    interval: Duration,
+    mut reload: impl FnMut() -> F,
+    mut render: impl FnMut(&Config) -> Result<()>,
+) -> Result<()>
+where
+    F: Future<Output = Result<Config>>,
+{
+    loop {
+        crossterm::execute!(
+            std::io::stdout(),
+            crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+            crossterm::cursor::MoveTo(0, 0)
+        )?;
+        render(&config)?;
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            () = tokio::time::sleep(interval) => {}
+        }
+        config = reload().await?;
+    }
+}
+
+/// `Config last modified: 2 hours ago`, or `None` when the file's mtime
+/// can't be read (e.g. config was never saved).
+fn config_modified_line(path: &Path, now: SystemTime) -> Option<String> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let age = now.duration_since(modified).unwrap_or_default();
+    Some(format!("Config last modified: {}", format_age(age)))
+}
+
+/// Percentage of non-coming-soon integrations that are active (0–100).
+fn health_score(config: &Config, entries: &[&IntegrationEntry]) -> u32 {
+    let statuses: Vec<_> = entries
+        .iter()
+        .map(|e| resolve_status(e, config))
+        .filter(|status| *status != IntegrationStatus::ComingSoon)
+        .collect();
+    if statuses.is_empty() {
+        return 0;
+    }
+
+    let active = statuses
+        .iter()
+        .filter(|status| status.is_configured())
+        .count();
+    u32::try_from(active * 100 / statuses.len()).unwrap_or(100)
+}
+
+/// `--debug` or `ZEROCLAW_DEBUG=1` turns on contributor diagnostics.
+fn debug_enabled(flag: bool, env: Option<&str>) -> bool {
+    flag || env == Some("1")
+}
+
+/// `Registry: src/integrations/registry.rs (77 entries)`, printed in debug
+/// mode so contributors know which file to edit.
+fn registry_source_line(count: usize) -> String {
+    format!("Registry: {} ({count} entries)", registry::SOURCE_PATH)
+}
+
+/// Which field `integrations list` groups entries under
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupBy {
+    Category,
+    Status,
+    Maturity,
+}
+
+fn parse_group_by(input: &str) -> Option<GroupBy> {
+    match input.to_lowercase().as_str() {
+        "category" => Some(GroupBy::Category),
+        "status" => Some(GroupBy::Status),
+        "maturity" => Some(GroupBy::Maturity),
+        _ => None,
+    }
+}
+
+/// Order of entries in `integrations list`, within each group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortBy {
+    Name,
+    Category,
+    /// Most configured first, then by name
+    Status,
+}
+
+fn parse_sort_by(input: &str) -> Option<SortBy> {
+    match input.to_lowercase().as_str() {
+        "name" => Some(SortBy::Name),
+        "category" => Some(SortBy::Category),
+        "status" => Some(SortBy::Status),
+        _ => None,
+    }
+}
+
+/// Stable-sort `matches` by `sort_by`, breaking ties by name. Categories
+/// follow `category_order`.
+fn sort_entries(
+    matches: &mut [(&IntegrationEntry, IntegrationStatus)],
+    sort_by: SortBy,
+    category_order: &[IntegrationCategory],
+) {
+    let category_rank = |category| category_order.iter().position(|c| *c == category);
+    matches.sort_by(|(a, a_status), (b, b_status)| {
+        let by_name = || a.name.to_lowercase().cmp(&b.name.to_lowercase());
+        match sort_by {
+            SortBy::Name => by_name(),
+            SortBy::Category => category_rank(a.category)
+                .cmp(&category_rank(b.category))
+                .then_with(by_name),
+            SortBy::Status => b_status.cmp(a_status).then_with(by_name),
+        }
+    });
+}
+
+/// Bucket entries under `group_by` headings in a fixed order (categories
+/// follow `category_order`), keeping the incoming order within each group
+/// and dropping empty groups.
+fn group_entries<'a>(
+    matches: Vec<(&'a IntegrationEntry, IntegrationStatus)>,
+    group_by: GroupBy,
+    category_order: &[IntegrationCategory],
+) -> Vec<(&'static str, Vec<(&'a IntegrationEntry, IntegrationStatus)>)> {
+    let heading = |entry: &IntegrationEntry, status: &IntegrationStatus| match group_by {
+        GroupBy::Category => entry.category.label(),
+        GroupBy::Status => status_group_label(status),
+        GroupBy::Maturity => entry.maturity.label(),
+    };
+    let order: Vec<&'static str> = match group_by {
+        GroupBy::Category => category_order.iter().map(|cat| cat.label()).collect(),
+        GroupBy::Status => vec![
+            "Active",
+            "Degraded",
+            "Auth Expired",
+            "Available",
+            "Coming Soon",
+        ],
+        GroupBy::Maturity => [Maturity::Stable, Maturity::Beta, Maturity::Experimental]
+            .iter()
+            .map(|m| m.label())
+            .collect(),
+    };
+
+    let mut groups: Vec<(&'static str, Vec<_>)> =
+        order.into_iter().map(|label| (label, Vec::new())).collect();
+    for (entry, status) in matches {
+        let label = heading(entry, &status);
+        if let Some((_, members)) = groups.iter_mut().find(|(l, _)| *l == label) {
+            members.push((entry, status));
+        }
+    }
+    groups.retain(|(_, members)| !members.is_empty());
+    groups
+}
+
+/// Remove entries named in `names` from `groups`, returning them in `names`
+/// order. Groups left empty are dropped.
+fn take_pinned<'a, K>(
+    groups: &mut Vec<(K, Vec<(&'a IntegrationEntry, IntegrationStatus)>)>,
+    names: &[String],
+) -> Vec<(&'a IntegrationEntry, IntegrationStatus)> {
+    let mut pinned = Vec::new();
+    for name in names {
+        for (_, members) in groups.iter_mut() {
+            if let Some(pos) = members
+                .iter()
+                .position(|(entry, _)| entry.name.eq_ignore_ascii_case(name))
+            {
+                pinned.push(members.remove(pos));
+            }
+        }
+    }
+    groups.retain(|(_, members)| !members.is_empty());
+    pinned
+}
+
+/// One `integrations list` row: icon, name, maturity badge, description.
+/// `inline_category` adds a `[Category]` label after the name for the
+/// ungrouped view.
+fn entry_row(
+    entry: &IntegrationEntry,
+    status: &IntegrationStatus,
+    inline_category: bool,
+    show_status: bool,
+) -> String {
+    format!(
+        "    {}{:<20} {}{}{}{}",
+        if show_status {
+            format!("{} ", status_icon(status))
+        } else {
+            String::new()
+        },
+        entry.name,
+        if inline_category {
+            format!("{} ", console::style(format!("[{}]", entry.category)).dim())
+        } else {
+            String::new()
+        },
+        match maturity_badge(entry.maturity) {
+            "" => String::new(),
+            badge => format!("{} ", console::style(badge).yellow()),
+        },
+        match status {
+            IntegrationStatus::AuthExpired { .. } if show_status => {
+                let age = auth_expired_age(status, chrono::Utc::now())
+                    .map(|age| format!(" ({age})"))
+                    .unwrap_or_default();
+                format!("{} ", console::style(format!("Auth Expired{age}")).red())
+            }
+            _ => String::new(),
+        },
+        console::style(entry.description).dim()
+    )
+}
+
+/// Keep only the first `max` entries across all groups, dropping groups that
+/// end up empty. Returns the number of entries before truncation.
+fn truncate_groups<K, T>(groups: &mut Vec<(K, Vec<T>)>, max: usize) -> usize {
+    let mut total = 0usize;
+    let mut remaining = max;
+    for (_, cat_entries) in groups.iter_mut() {
+        total += cat_entries.len();
+        cat_entries.truncate(remaining);
+        remaining -= cat_entries.len();
+    }
+    groups.retain(|(_, cat_entries)| !cat_entries.is_empty());
+    total
+}
+
+/// `(active_and_available_count, coming_soon_count)` for a category's
+/// entries; the category heading shows the first and notes the second.
+fn category_counts(cat_entries: &[(&IntegrationEntry, IntegrationStatus)]) -> (usize, usize) {
+    let coming_soon = cat_entries
+        .iter()
+        .filter(|(_, status)| *status == IntegrationStatus::ComingSoon)
+        .count();
+    (cat_entries.len() - coming_soon, coming_soon)
+}
+
+/// Footer for `integrations list`, e.g. "Showing 8 of 42 integrations (31
+/// hidden by filters, 3 for other platforms)." `other_platforms` entries
+/// don't run on this OS and are left out unless `--all-platforms`.
+fn list_footer(shown: usize, total: usize, other_platforms: usize) -> String {
+    let filtered = total.saturating_sub(shown).saturating_sub(other_platforms);
+    let mut hidden = Vec::new();
+    if filtered > 0 {
+        hidden.push(format!("{filtered} hidden by filters"));
+    }
+    if other_platforms > 0 {
+        hidden.push(format!("{other_platforms} for other platforms"));
+    }
+    if hidden.is_empty() {
+        format!("Showing all {total} integrations.")
+    } else {
+        format!(
+            "Showing {shown} of {total} integrations ({}).",
+            hidden.join(", ")
+        )
+    }
+}
+
+/// Serialize matches as a JSON array of `IntegrationSummary` objects.
+fn summaries_json(matches: &[(&IntegrationEntry, IntegrationStatus)]) -> serde_json::Value {
+    let summaries: Vec<_> = matches
+        .iter()
+        .map(|(entry, status)| IntegrationSummary::new(entry, status.clone()))
+        .collect();
+    serde_json::to_value(summaries).unwrap_or_default()
+}
+
+/// One category label per line, in `IntegrationCategory::all()` order.
+fn render_category_labels() -> String {
+    let mut out = String::new();
+    for cat in IntegrationCategory::all() {
+        let _ = writeln!(out, "{}", cat.label());
+    }
+    out
+}
+
+/// One `Label (N active / M total)` line per non-empty category.
+fn render_summary(
+    categories: &BTreeMap<IntegrationCategory, Vec<&IntegrationEntry>>,
+    order: &[IntegrationCategory],
+    config: &Config,
+) -> String {
+    let mut out = String::new();
+    for (category, entries) in order
+        .iter()
+        .filter_map(|category| Some((category, categories.get(category)?)))
+    {
+        if entries.is_empty() {
+            continue;
+        }
+        let active = entries
+            .iter()
+            .filter(|e| resolve_status(e, config).is_configured())
+            .count();
+        let _ = writeln!(
+            out,
+            "{category:<18} ({active} active / {} total)",
+            entries.len()
+        );
+    }
+    out
+}
+
+/// One `name\tcategory\tstatus\tdescription` line per entry, optionally
+/// preceded by a header row. Tabs and newlines inside fields become spaces so
+/// every line keeps exactly four columns.
+fn render_tsv(rows: &[&(&IntegrationEntry, IntegrationStatus)], headers: bool) -> String {
+    let clean = |field: &str| field.replace(['\t', '\n', '\r'], " ");
+    let mut out = String::new();
+    if headers {
+        out.push_str("name\tcategory\tstatus\tdescription\n");
+    }
+    for (entry, status) in rows {
+        let _ = writeln!(
+            out,
+            "{}\t{}\t{}\t{}",
+            clean(entry.name),
+            clean(entry.category.label()),
+            clean(&status.to_string()),
+            clean(entry.description)
+        );
+    }
+    out
+}
+
+/// Render names as a `ZEROCLAW_INTEGRATIONS=(...)` bash array assignment.
+fn render_shell_array(names: &[&str]) -> String {
+    let mut out = String::from("ZEROCLAW_INTEGRATIONS=(\n");
+    for name in names {
+        let escaped = name
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('$', "\\$")
+            .replace('`', "\\`");
+        let _ = writeln!(out, "  \"{escaped}\"");
+    }
+    out.push_str(")\n");
+    out
+}
+
+/// One-line summary printed by `integrations list --dry-run`.
+fn dry_run_summary(statuses: &[&IntegrationStatus]) -> String {
+    let count_of = |wanted: fn(&IntegrationStatus) -> bool| {
+        statuses.iter().filter(|status| wanted(status)).count()
+    };
+    let active = count_of(|s| matches!(s, IntegrationStatus::Active));
+    let available = count_of(|s| matches!(s, IntegrationStatus::Available));
+    let coming_soon = count_of(|s| matches!(s, IntegrationStatus::ComingSoon));
+    let degraded = count_of(|s| matches!(s, IntegrationStatus::Degraded(_)));
+    let auth_expired = count_of(|s| matches!(s, IntegrationStatus::AuthExpired { .. }));
+
+    let mut breakdown = format!("{active} active, {available} available");
+    if coming_soon > 0 {
+        let _ = write!(breakdown, ", {coming_soon} coming soon");
+    }
+    if degraded > 0 {
+        let _ = write!(breakdown, ", {degraded} degraded");
+    }
+    if auth_expired > 0 {
+        let _ = write!(breakdown, ", {auth_expired} auth expired");
+    }
+
+    format!(
+        "Would display: {} integrations ({breakdown})",
+        statuses.len()
+    )
+}
+
+/// `--verbose` lines under an entry row: its category and the config keys
+/// (required or optional) that are set.
+pub(super) fn verbose_details(entry: &IntegrationEntry, config_json: &serde_json::Value) -> String {
+    let set: Vec<_> = entry
+        .required_config_keys
+        .iter()
+        .chain(entry.optional_config_keys)
+        .copied()
+        .filter(|key| config_key_is_set(config_json, key))
+        .collect();
+    let mut out = format!(
+        "        Category: {} {}",
+        entry.category.icon(),
+        entry.category
+    );
+    if !set.is_empty() {
+        let _ = write!(out, "\n        Config set: {}", set.join(", "));
+    }
+    out
+}
+
+/// `--compact` line for one entry: `name icon status`, unstyled so it pipes
+/// cleanly into `grep`/`awk`.
+pub(super) fn compact_row(entry: &IntegrationEntry, status: &IntegrationStatus) -> String {
+    format!("{:<24} {} {status}", entry.name, status_icon(status))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::tests::entry_with_status;
+    use crate::integrations::{
+        handle_command, list_integrations, AuthType, MessageType, Platform, PricingModel,
+    };
+
+    /// The `integrations` array of `--output json` output.
+    fn listed_json(out: &str) -> Vec<serde_json::Value> {
+        let json: serde_json::Value = serde_json::from_str(out).unwrap();
+        serde_json::from_value(json["integrations"].clone()).unwrap()
+    }
+
+    #[test]
+    fn list_json_ends_with_registry_checksum() {
+        let config = Config::default();
+        let options = ListOptions {
+            output: Some("json"),
+            ..ListOptions::default()
+        };
+        let out = render_list(&config, &options).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(json["registry_checksum"], registry::checksum());
+        assert!(!listed_json(&out).is_empty());
+        let checksum_at = out.find("\"registry_checksum\"").unwrap();
+        assert!(checksum_at > out.rfind("\"name\"").unwrap());
+    }
+
+    #[test]
+    fn health_score_counts_active_over_non_coming_soon() {
+        let config = Config::default();
+        let active = || entry_with_status(|_| IntegrationStatus::Active);
+        let available = || entry_with_status(|_| IntegrationStatus::Available);
+        let soon = || entry_with_status(|_| IntegrationStatus::ComingSoon);
+
+        assert_eq!(
+            health_score(&config, &[&available(), &available(), &soon()]),
+            0
+        );
+        assert_eq!(
+            health_score(&config, &[&active(), &available(), &soon()]),
+            50
+        );
+        assert_eq!(health_score(&config, &[&active(), &active(), &soon()]), 100);
+        assert_eq!(health_score(&config, &[&soon()]), 0);
+    }
+
+    #[test]
+    fn degraded_entry_counts_as_active_in_every_summary() {
+        let config = Config::default();
+        let degraded = entry_with_status(|_| IntegrationStatus::Degraded("slow".into()));
+        let entries = [&degraded];
+
+        assert_eq!(health_score(&config, &entries), 100);
+
+        let categories = BTreeMap::from([(degraded.category, entries.to_vec())]);
+        let summary = render_summary(&categories, IntegrationCategory::all(), &config);
+        assert!(summary.contains("(1 active / 1 total)"), "{summary}");
+
+        let counts = crate::integrations::stats_counts(&entries, &config);
+        assert_eq!(counts[&degraded.category], [1, 0, 0]);
+    }
+
+    #[test]
+    fn auth_expired_shows_red_icon_label_and_age() {
+        use chrono::TimeZone;
+
+        let now = chrono::Utc.with_ymd_and_hms(2026, 3, 4, 12, 0, 0).unwrap();
+        let since = now - chrono::Duration::days(3);
+        let status = IntegrationStatus::AuthExpired { since: Some(since) };
+        assert_eq!(status_icon(&status), "🔴");
+        assert_eq!(status.to_string(), "Auth Expired");
+        assert_eq!(
+            auth_expired_age(&status, now).as_deref(),
+            Some("Expired 3 days ago")
+        );
+        assert_eq!(
+            auth_expired_age(&IntegrationStatus::AuthExpired { since: None }, now),
+            None
+        );
+        assert_eq!(auth_expired_age(&IntegrationStatus::Active, now), None);
+
+        let entry = entry_with_status(|_| IntegrationStatus::Available);
+        let row = console::strip_ansi_codes(&entry_row(&entry, &status, false, true)).into_owned();
+        assert!(row.starts_with("    🔴 ") && row.contains("Auth Expired (Expired "));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "telegram")]
+    async fn live_loop_reloads_config_and_reevaluates_status_between_renders() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let entries = registry::all_integrations();
+        let telegram = entries.iter().find(|e| e.name == "Telegram").unwrap();
+        let reloads = AtomicUsize::new(0);
+        let mut statuses = Vec::new();
+
+        // The second reload fails, which is what ends the loop.
+        let result = Box::pin(live_loop(
+            Config::default(),
+            Duration::ZERO,
+            || {
+                let reload = reloads.fetch_add(1, Ordering::SeqCst);
+                let mut config = Config::default();
+                config.channels_config.telegram = Some(crate::config::TelegramConfig {
+                    bot_token: "123:ABC".into(),
+                    allowed_users: vec![],
+                    stream_mode: crate::config::StreamMode::default(),
+                    draft_update_interval_ms: 1000,
+                    interrupt_on_new_message: false,
+                    mention_only: false,
+                    group_reply: None,
+                    base_url: None,
+                });
+                async move {
+                    anyhow::ensure!(reload == 0, "stop");
+                    Ok(config)
+                }
+            },
+            |config| {
+                statuses.push(resolve_status(telegram, config));
+                Ok(())
+            },
+        ))
+        .await;
+
+        assert_eq!(result.unwrap_err().to_string(), "stop");
+        assert_eq!(reloads.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            statuses,
+            vec![IntegrationStatus::Available, IntegrationStatus::Active]
+        );
+    }
+
+    #[test]
+    fn config_modified_line_reports_age_of_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "").unwrap();
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+        let line = config_modified_line(&path, modified + Duration::from_secs(2 * 3_600));
+        assert_eq!(line.as_deref(), Some("Config last modified: 2 hours ago"));
+
+        assert!(config_modified_line(&dir.path().join("missing.toml"), modified).is_none());
+    }
+
+    #[test]
+    fn dry_run_summary_reports_counts_by_status() {
+        let active = IntegrationStatus::Active;
+        let available = IntegrationStatus::Available;
+        let statuses = vec![&active, &active, &available];
+        assert_eq!(
+            dry_run_summary(&statuses),
+            "Would display: 3 integrations (2 active, 1 available)"
+        );
+
+        let soon = IntegrationStatus::ComingSoon;
+        let statuses = vec![&active, &soon];
+        assert_eq!(
+            dry_run_summary(&statuses),
+            "Would display: 2 integrations (1 active, 0 available, 1 coming soon)"
+        );
+    }
+
+    #[test]
+    fn dry_run_list_prints_only_the_summary_line() {
+        let config = Config::default();
+        let options = ListOptions {
+            dry_run: true,
+            all_platforms: true,
+            ..ListOptions::default()
+        };
+        let out = render_list(&config, &options).unwrap();
+        assert_eq!(out.lines().count(), 1);
+        assert!(out.starts_with(&format!(
+            "Would display: {} integrations (",
+            registry::all_integrations().len()
+        )));
+    }
+
+    #[test]
+    fn group_by_maturity_lists_experimental_header() {
+        let config = Config::default();
+        let entries = registry::all_integrations();
+        assert!(entries.iter().any(|e| e.maturity == Maturity::Experimental));
+
+        let matches: Vec<_> = entries
+            .iter()
+            .map(|e| (*e, resolve_status(e, &config)))
+            .collect();
+        let groups = group_entries(matches, GroupBy::Maturity, IntegrationCategory::all());
+        let headings: Vec<_> = groups.iter().map(|(label, _)| *label).collect();
+        assert_eq!(headings, vec!["Stable", "Beta", "Experimental"]);
+        assert!(groups[2]
+            .1
+            .iter()
+            .all(|(e, _)| e.maturity == Maturity::Experimental));
+        assert!(parse_group_by("bogus").is_none());
+    }
+
+    #[test]
+    fn truncate_groups_keeps_exactly_max_entries_across_categories() {
+        let mut groups = vec![
+            (IntegrationCategory::Chat, vec![1, 2, 3]),
+            (IntegrationCategory::AiModel, vec![4, 5]),
+            (IntegrationCategory::Social, vec![6]),
+        ];
+
+        assert_eq!(truncate_groups(&mut groups, 4), 6);
+        let kept: Vec<_> = groups.iter().flat_map(|(_, e)| e.iter().copied()).collect();
+        assert_eq!(kept, vec![1, 2, 3, 4]);
+        assert_eq!(groups.len(), 2);
+
+        let mut groups = vec![(IntegrationCategory::Chat, vec![1, 2])];
+        assert_eq!(truncate_groups(&mut groups, 10), 2);
+        assert_eq!(groups[0].1.len(), 2);
+    }
+
+    #[test]
+    fn category_counts_exclude_coming_soon_from_main_count() {
+        let active = entry_with_status(|_| IntegrationStatus::Active);
+        let available = entry_with_status(|_| IntegrationStatus::Available);
+        let soon = entry_with_status(|_| IntegrationStatus::ComingSoon);
+        let cat_entries = vec![
+            (&active, IntegrationStatus::Active),
+            (&available, IntegrationStatus::Available),
+            (&soon, IntegrationStatus::ComingSoon),
+            (&soon, IntegrationStatus::ComingSoon),
+        ];
+
+        assert_eq!(category_counts(&cat_entries), (2, 2));
+        assert_eq!(category_counts(&cat_entries[..2]), (2, 0));
+        assert_eq!(category_counts(&[]), (0, 0));
+    }
+
+    #[test]
+    fn list_footer_reports_entries_hidden_by_category_filter() {
+        let entries = registry::all_integrations();
+        let chat = entries
+            .iter()
+            .filter(|e| e.category == IntegrationCategory::Chat)
+            .count();
+        let hidden = entries.len() - chat;
+        assert!(hidden > 0);
+
+        assert_eq!(
+            list_footer(chat, entries.len(), 0),
+            format!(
+                "Showing {chat} of {} integrations ({hidden} hidden by filters).",
+                entries.len()
+            )
+        );
+        assert_eq!(list_footer(3, 3, 0), "Showing all 3 integrations.");
+    }
+
+    #[test]
+    fn list_footer_counts_other_platforms_apart_from_filters() {
+        assert_eq!(
+            list_footer(70, 77, 7),
+            "Showing 70 of 77 integrations (7 for other platforms)."
+        );
+        assert_eq!(
+            list_footer(10, 77, 7),
+            "Showing 10 of 77 integrations (60 hidden by filters, 7 for other platforms)."
+        );
+
+        let config = Config::default();
+        let out =
+            console::strip_ansi_codes(&render_list(&config, &ListOptions::default()).unwrap())
+                .into_owned();
+        assert!(!out.contains("hidden by filters"));
+    }
+
+    #[test]
+    fn list_category_order_puts_listed_categories_first() {
+        let config = Config::default();
+        let options = ListOptions {
+            category_order: Some("ai, chat"),
+            all_platforms: true,
+            ..ListOptions::default()
+        };
+        let out = console::strip_ansi_codes(&render_list(&config, &options).unwrap()).into_owned();
+        let ai = out.find(IntegrationCategory::AiModel.label()).unwrap();
+        let chat = out.find(IntegrationCategory::Chat.label()).unwrap();
+        let tools = out
+            .find(IntegrationCategory::ToolsAutomation.label())
+            .unwrap();
+        assert!(ai < chat && chat < tools);
+
+        assert_eq!(
+            category_order_arg(Some("tools,ai,tools")).unwrap()[..3],
+            [
+                IntegrationCategory::ToolsAutomation,
+                IntegrationCategory::AiModel,
+                IntegrationCategory::Chat,
+            ]
+        );
+        assert!(category_order_arg(Some("ai,nope")).is_err());
+    }
+
+    #[test]
+    fn sort_entries_by_status_puts_most_configured_first() {
+        let entries = registry::all_integrations();
+        let entry = |name| *entries.iter().find(|e| e.name == name).unwrap();
+        let mut matches = vec![
+            (entry("Slack"), IntegrationStatus::ComingSoon),
+            (entry("Discord"), IntegrationStatus::Available),
+            (entry("Telegram"), IntegrationStatus::Active),
+            (entry("Cron"), IntegrationStatus::Available),
+        ];
+
+        sort_entries(&mut matches, SortBy::Status, IntegrationCategory::all());
+        let names: Vec<_> = matches.iter().map(|(e, _)| e.name).collect();
+        assert_eq!(names, ["Telegram", "Cron", "Discord", "Slack"]);
+
+        let options = ListOptions {
+            sort_by: Some("popularity"),
+            ..ListOptions::default()
+        };
+        assert!(render_list(&Config::default(), &options).is_err());
+    }
+
+    #[test]
+    fn list_category_headings_carry_the_category_icon() {
+        let config = Config::default();
+        let options = ListOptions {
+            category: Some("chat"),
+            all_platforms: true,
+            ..ListOptions::default()
+        };
+        let out = render_list(&config, &options).unwrap();
+        assert!(console::strip_ansi_codes(&out).contains("  💬 Chat Providers ("));
+
+        let options = ListOptions {
+            group_by: Some("status"),
+            ..options
+        };
+        let out = render_list(&config, &options).unwrap();
+        assert!(!out.contains('💬'));
+    }
+
+    #[test]
+    fn registry_source_line_points_at_registry_in_debug_mode() {
+        assert!(debug_enabled(true, None));
+        assert!(debug_enabled(false, Some("1")));
+        assert!(!debug_enabled(false, Some("0")));
+
+        let line = registry_source_line(registry::all_integrations().len());
+        assert!(line.contains("registry"), "unexpected line: {line}");
+        assert!(line.ends_with(" entries)"));
+    }
+
+    #[test]
+    fn render_summary_prints_one_line_per_non_empty_category() {
+        let config = Config::default();
+        let entries = registry::all_integrations();
+        let mut categories: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for entry in entries.iter().filter(|e| {
+            matches!(
+                e.category,
+                IntegrationCategory::Chat | IntegrationCategory::Social
+            )
+        }) {
+            categories.entry(entry.category).or_default().push(*entry);
+        }
+        categories.insert(IntegrationCategory::SmartHome, Vec::new());
+
+        let out = render_summary(&categories, IntegrationCategory::all(), &config);
+        let non_empty = categories.values().filter(|v| !v.is_empty()).count();
+        assert_eq!(out.lines().count(), non_empty);
+        let chat_total = categories[&IntegrationCategory::Chat].len();
+        assert!(out
+            .lines()
+            .next()
+            .unwrap()
+            .starts_with("Chat Providers     ("));
+        assert!(out.contains(&format!(" / {chat_total} total)")));
+    }
+
+    #[test]
+    fn compact_output_is_one_bare_line_per_entry() {
+        let config = Config::default();
+        let options = ListOptions {
+            output: Some("compact"),
+            all_platforms: true,
+            ..ListOptions::default()
+        };
+        let out = render_list(&config, &options).unwrap();
+        assert_eq!(console::strip_ansi_codes(&out), out);
+        assert_eq!(out.lines().count(), registry::all_integrations().len());
+        assert!(!out.contains("Setup completeness"));
+        let telegram = out.lines().find(|l| l.starts_with("Telegram ")).unwrap();
+        assert!(telegram.ends_with("⚪ Available"));
+    }
+
+    #[test]
+    fn verbose_output_adds_category_and_set_config_keys() {
+        let mut config = Config::default();
+        config.default_provider = Some("openrouter".into());
+        let options = ListOptions {
+            output: Some("verbose"),
+            ..ListOptions::default()
+        };
+        let out = console::strip_ansi_codes(&render_list(&config, &options).unwrap()).into_owned();
+        assert!(out.contains("Category: "));
+        assert!(out.contains("Config set: default_provider"));
+        assert!(!out.contains("sha256:"));
+    }
+
+    #[test]
+    fn search_output_format_env_ignores_list_only_formats() {
+        assert_eq!(search_output_format(None), OutputFormat::Text);
+        assert_eq!(search_output_format(Some("Compact")), OutputFormat::Compact);
+        assert_eq!(search_output_format(Some("verbose")), OutputFormat::Verbose);
+        assert_eq!(search_output_format(Some("prometheus")), OutputFormat::Text);
+        assert_eq!(search_output_format(Some("bogus")), OutputFormat::Text);
+    }
+
+    #[test]
+    fn list_tsv_has_four_columns_per_line() {
+        let config = Config::default();
+        let render = |headers| {
+            let options = ListOptions {
+                output: Some("tsv"),
+                headers,
+                all_platforms: true,
+                ..ListOptions::default()
+            };
+            render_list(&config, &options).unwrap()
+        };
+
+        let out = render(false);
+        assert_eq!(out.lines().count(), registry::all_integrations().len());
+        assert!(out.lines().all(|line| line.matches('\t').count() == 3));
+        assert!(out.lines().any(|line| line.starts_with("Shell\t")));
+
+        let with_headers = render(true);
+        assert_eq!(
+            with_headers.lines().next(),
+            Some("name\tcategory\tstatus\tdescription")
+        );
+        assert!(with_headers
+            .lines()
+            .all(|line| line.matches('\t').count() == 3));
+    }
+
+    #[test]
+    fn render_category_labels_prints_one_bare_label_per_category() {
+        let out = render_category_labels();
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], "Chat Providers");
+        assert_eq!(lines[8], "Platforms");
+        assert!(lines
+            .iter()
+            .all(|line| !line.starts_with(' ') && !line.contains('\x1b')));
+    }
+
+    #[test]
+    fn summaries_json_includes_name_category_and_status() {
+        let entries = registry::all_integrations();
+        let shell = entries.iter().find(|e| e.name == "Shell").unwrap();
+        let value = summaries_json(&[(shell, IntegrationStatus::Active)]);
+
+        assert_eq!(
+            value,
+            serde_json::json!([{
+                "name": "Shell",
+                "description": shell.description,
+                "category": "Tools & Automation",
+                "status": "Active",
+            }])
+        );
+    }
+
+    #[tokio::test]
+    async fn pin_unpin_round_trips_through_config_and_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.config_path = dir.path().join("config.toml");
+        config.workspace_dir = dir.path().join("workspace");
+        let reload = |path: &Path| -> Config {
+            let mut loaded: Config =
+                toml::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+            loaded.config_path = path.to_path_buf();
+            loaded
+        };
+
+        for name in ["shell", "OpenRouter"] {
+            let pin = crate::IntegrationCommands::Pin { name: name.into() };
+            handle_command(pin, &config).await.unwrap();
+            config = reload(&config.config_path);
+        }
+        assert_eq!(config.integrations.pinned.names, ["Shell", "OpenRouter"]);
+
+        let out =
+            console::strip_ansi_codes(&render_list(&config, &ListOptions::default()).unwrap())
+                .into_owned();
+        let pinned_at = out.find("★ Pinned (2)").unwrap();
+        assert!(pinned_at < out.find("Chat Providers").unwrap());
+        assert!(
+            out[pinned_at..].find("Shell").unwrap() < out[pinned_at..].find("OpenRouter").unwrap()
+        );
+        assert_eq!(out.matches(" Shell ").count(), 1);
+
+        let unpin = crate::IntegrationCommands::Unpin {
+            name: "SHELL".into(),
+        };
+        handle_command(unpin, &config).await.unwrap();
+        config = reload(&config.config_path);
+        assert_eq!(config.integrations.pinned.names, ["OpenRouter"]);
+    }
+
+    #[test]
+    fn list_with_multiple_categories_groups_only_those_in_order() {
+        let config = Config::default();
+        let options = ListOptions {
+            category: Some("tools,chat"),
+            all_platforms: true,
+            ..ListOptions::default()
+        };
+        let out = console::strip_ansi_codes(&render_list(&config, &options).unwrap()).into_owned();
+        let chat = out.find(IntegrationCategory::Chat.label()).unwrap();
+        let tools = out
+            .find(IntegrationCategory::ToolsAutomation.label())
+            .unwrap();
+        assert!(chat < tools);
+        assert!(!out.contains(IntegrationCategory::AiModel.label()));
+
+        let summaries = list_integrations(
+            &config,
+            &[IntegrationCategory::Chat, IntegrationCategory::Social],
+            None,
+        )
+        .unwrap();
+        assert!(summaries.iter().all(|s| matches!(
+            s.category,
+            IntegrationCategory::Chat | IntegrationCategory::Social
+        )));
+        assert!(summaries
+            .iter()
+            .any(|s| s.category == IntegrationCategory::Social));
+    }
+
+    #[test]
+    fn render_shell_array_wraps_quoted_names() {
+        let out = render_shell_array(&["Telegram", "Twitter/X", "Say \"hi\" $HOME"]);
+        assert!(out.starts_with("ZEROCLAW_INTEGRATIONS=("));
+        assert!(out.trim_end().ends_with(')'));
+        assert!(out.contains("  \"Telegram\"\n"));
+        assert!(out.contains("  \"Twitter/X\"\n"));
+        assert!(out.contains(r#"  "Say \"hi\" \$HOME""#));
+    }
+
+    #[test]
+    fn maturity_badge_marks_only_non_stable_entries() {
+        assert_eq!(maturity_badge(Maturity::Stable), "");
+        assert_eq!(maturity_badge(Maturity::Beta), "[beta]");
+        assert_eq!(maturity_badge(Maturity::Experimental), "[experimental]");
+    }
+
+    #[test]
+    fn list_pricing_filter_keeps_only_matching_model() {
+        let config = Config::default();
+        let entries = registry::all_integrations();
+        for (input, model) in [
+            ("free", PricingModel::Free),
+            ("freemium", PricingModel::Freemium),
+            ("paid-only", PricingModel::PaidOnly),
+            ("open-source", PricingModel::OpenSource),
+        ] {
+            let options = ListOptions {
+                pricing: Some(input),
+                output: Some("json"),
+                all_platforms: true,
+                ..ListOptions::default()
+            };
+            let out = render_list(&config, &options).unwrap();
+            let listed = listed_json(&out);
+            let expected = entries.iter().filter(|e| e.pricing_model == model).count();
+            assert!(expected > 0, "no {model:?} entries in the registry");
+            assert_eq!(listed.len(), expected, "--pricing {input}");
+            for item in &listed {
+                let name = item["name"].as_str().unwrap();
+                let entry = entries.iter().find(|e| e.name == name).unwrap();
+                assert_eq!(
+                    entry.pricing_model, model,
+                    "{name} listed for --pricing {input}"
+                );
+            }
+        }
+
+        let options = ListOptions {
+            pricing: Some("cheap"),
+            ..ListOptions::default()
+        };
+        assert!(render_list(&config, &options).is_err());
+    }
+
+    #[test]
+    fn list_auth_type_filter_keeps_only_matching_type() {
+        let config = Config::default();
+        let entries = registry::all_integrations();
+        for (input, auth) in [
+            ("api-key", AuthType::ApiKey),
+            ("oauth2", AuthType::OAuth2),
+            ("bearer", AuthType::BearerToken),
+            ("basic", AuthType::BasicAuth),
+            ("none", AuthType::None),
+        ] {
+            let options = ListOptions {
+                auth_type: Some(input),
+                output: Some("json"),
+                all_platforms: true,
+                ..ListOptions::default()
+            };
+            let out = render_list(&config, &options).unwrap();
+            let listed = listed_json(&out);
+            let expected = entries
+                .iter()
+                .filter(|e| e.authentication_type == auth)
+                .count();
+            assert!(expected > 0, "no {auth:?} entries in the registry");
+            assert_eq!(listed.len(), expected, "--auth-type {input}");
+            for item in &listed {
+                let name = item["name"].as_str().unwrap();
+                let entry = entries.iter().find(|e| e.name == name).unwrap();
+                assert_eq!(
+                    entry.authentication_type, auth,
+                    "{name} listed for --auth-type {input}"
+                );
+            }
+        }
+
+        let options = ListOptions {
+            auth_type: Some("kerberos"),
+            ..ListOptions::default()
+        };
+        assert!(render_list(&config, &options).is_err());
+    }
+
+    #[test]
+    fn list_supports_filter_keeps_entries_handling_that_message_type() {
+        let config = Config::default();
+        let options = ListOptions {
+            supports: Some("image"),
+            output: Some("json"),
+            all_platforms: true,
+            ..ListOptions::default()
+        };
+        let out = render_list(&config, &options).unwrap();
+        let listed = listed_json(&out);
+        let names: Vec<_> = listed.iter().map(|i| i["name"].as_str().unwrap()).collect();
+        assert!(names.contains(&"Signal") && names.contains(&"Slack"));
+        assert!(!names.contains(&"Nostr") && !names.contains(&"OpenRouter"));
+        for name in &names {
+            let entry = registry::find(name).unwrap();
+            assert!(entry.supported_message_types.contains(&MessageType::Image));
+        }
+
+        let options = ListOptions {
+            supports: Some("hologram"),
+            ..ListOptions::default()
+        };
+        assert!(render_list(&config, &options).is_err());
+    }
+
+    #[test]
+    fn list_data_residency_filter_matches_case_insensitively() {
+        let config = Config::default();
+        let options = ListOptions {
+            data_residency: Some("eu"),
+            output: Some("json"),
+            all_platforms: true,
+            ..ListOptions::default()
+        };
+        let out = render_list(&config, &options).unwrap();
+        let listed = listed_json(&out);
+        let names: Vec<&str> = listed
+            .iter()
+            .map(|item| item["name"].as_str().unwrap())
+            .collect();
+        let expected: Vec<&str> = registry::all_integrations()
+            .iter()
+            .filter(|e| e.data_residency == "EU")
+            .map(|e| e.name)
+            .collect();
+        assert!(names.contains(&"Mistral"));
+        assert!(!names.contains(&"OpenAI"));
+        assert_eq!(names.len(), expected.len());
+
+        let options = ListOptions {
+            data_residency: Some("Mars"),
+            ..options
+        };
+        let out = render_list(&config, &options).unwrap();
+        assert!(listed_json(&out).is_empty());
+    }
+
+    #[test]
+    fn shell_array_and_prometheus_outputs_start_with_their_headers() {
+        let config = Config::default();
+        let render = |output| {
+            let options = ListOptions {
+                output: Some(output),
+                ..ListOptions::default()
+            };
+            render_list(&config, &options).unwrap()
+        };
+
+        let shell = render("shell-array");
+        assert!(shell.starts_with("ZEROCLAW_INTEGRATIONS=(\n"));
+        assert!(shell.ends_with(")\n"));
+        assert!(shell.contains("  \"Telegram\"\n"));
+
+        let prom = render("prometheus");
+        let mut lines = prom.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("# HELP zeroclaw_integration_status "));
+        assert_eq!(
+            lines.next(),
+            Some("# TYPE zeroclaw_integration_status gauge")
+        );
+        assert!(prom.lines().skip(2).all(|line| !line.starts_with('#')));
+    }
+
+    #[test]
+    fn list_license_filter_splits_open_source_from_proprietary() {
+        let config = Config::default();
+        let listed = |license| {
+            let options = ListOptions {
+                license: Some(license),
+                output: Some("shell-array"),
+                ..ListOptions::default()
+            };
+            render_list(&config, &options).unwrap()
+        };
+
+        let open = listed("open-source");
+        assert!(open.contains("\"Shell\""));
+        assert!(!open.contains("\"OpenAI\""));
+        let proprietary = listed("proprietary");
+        assert!(proprietary.contains("\"OpenAI\""));
+        assert!(!proprietary.contains("\"Shell\""));
+    }
+
+    #[test]
+    fn list_hides_other_platforms_unless_all_platforms() {
+        let config = Config::default();
+        let listed = |all_platforms| {
+            let options = ListOptions {
+                output: Some("shell-array"),
+                all_platforms,
+                ..ListOptions::default()
+            };
+            render_list(&config, &options).unwrap()
+        };
+
+        let on_macos = Platform::current() == Some(Platform::MacOs);
+        assert_eq!(listed(false).contains("\"iMessage\""), on_macos);
+        assert!(listed(false).contains("\"Shell\""));
+        assert!(listed(true).contains("\"iMessage\""));
+        assert!(listed(true).contains("\"Windows\""));
+    }
+
+    #[test]
+    fn list_tag_filter_is_case_insensitive() {
+        let config = Config::default();
+        let options = ListOptions {
+            tag: Some("Self-Hosted"),
+            output: Some("shell-array"),
+            ..ListOptions::default()
+        };
+        let out = render_list(&config, &options).unwrap();
+        assert!(out.contains("\"Matrix\""));
+        assert!(out.contains("\"Home Assistant\""));
+        assert!(!out.contains("\"OpenRouter\""));
+    }
+
+    #[test]
+    fn list_self_hosted_filter_splits_local_from_hosted() {
+        let config = Config::default();
+        let listed = |self_hosted| {
+            let options = ListOptions {
+                self_hosted: Some(self_hosted),
+                output: Some("shell-array"),
+                all_platforms: true,
+                ..ListOptions::default()
+            };
+            render_list(&config, &options).unwrap()
+        };
+
+        let local = listed(true);
+        for name in ["Ollama", "Cron", "Webhooks", "Browser"] {
+            assert!(
+                local.contains(&format!("\"{name}\"")),
+                "{name} not self-hosted"
+            );
+        }
+        let hosted = listed(false);
+        for name in ["OpenRouter", "GitHub", "Telegram"] {
+            assert!(
+                hosted.contains(&format!("\"{name}\"")),
+                "{name} self-hosted"
+            );
+            assert!(!local.contains(&format!("\"{name}\"")));
+        }
+        assert!(!hosted.contains("\"Ollama\""));
+    }
+
+    #[test]
+    fn list_no_group_prints_flat_sorted_list_without_headers() {
+        let config = Config::default();
+        let options = ListOptions {
+            category: Some("tools"),
+            no_group: true,
+            ..ListOptions::default()
+        };
+        let out = console::strip_ansi_codes(&render_list(&config, &options).unwrap()).into_owned();
+        let rows: Vec<_> = out
+            .lines()
+            .filter(|line| line.contains("[Tools & Automation]"))
+            .collect();
+        assert!(rows.len() > 1);
+        assert!(!out
+            .lines()
+            .any(|line| line.starts_with("  Tools & Automation")));
+
+        let names: Vec<_> = rows
+            .iter()
+            .map(|row| row.split("[Tools").next().unwrap().trim())
+            .map(|row| row.split_once(' ').unwrap().1.trim().to_lowercase())
+            .collect();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    fn list_no_status_hides_status_icons() {
+        let config = Config::default();
+        let render = |no_status| {
+            let options = ListOptions {
+                no_status,
+                all_platforms: true,
+                ..ListOptions::default()
+            };
+            console::strip_ansi_codes(&render_list(&config, &options).unwrap()).into_owned()
+        };
+
+        assert!(render(false).contains('⚪'));
+        let out = render(true);
+        for icon in ["✅", "⚪", "🔜"] {
+            assert!(!out.contains(icon), "{icon} in --no-status output");
+        }
+        assert!(out.contains("    Shell "));
+        assert!(out.contains(IntegrationCategory::ToolsAutomation.label()));
+    }
+
+    #[test]
+    fn compact_list_has_no_blank_lines() {
+        let config = Config::default();
+        let render = |render_mode| {
+            let options = ListOptions {
+                render_mode,
+                ..ListOptions::default()
+            };
+            console::strip_ansi_codes(&render_list(&config, &options).unwrap()).into_owned()
+        };
+
+        let compact = render(RenderMode::Compact);
+        assert!(!compact.contains("\n\n"));
+        assert!(compact.lines().all(|line| !line.trim().is_empty()));
+        let spacious = render(RenderMode::Spacious);
+        assert!(spacious.contains("\n\n"));
+        assert_eq!(
+            compact.lines().collect::<Vec<_>>(),
+            spacious
+                .lines()
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn compact_flag_selects_render_mode_not_one_line_output() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            list: IntegrationListArgs,
+        }
+        let parse = |argv: &[&str]| <Cli as clap::Parser>::try_parse_from(argv).map(|c| c.list);
+
+        let args = parse(&["list", "--compact"]).unwrap();
+        let options = ListOptions::from_args(&args, None, None);
+        assert_eq!(options.render_mode, RenderMode::Compact);
+        assert_eq!(options.output, None);
+
+        let args = parse(&["list", "--output", "compact"]).unwrap();
+        let options = ListOptions::from_args(&args, None, None);
+        assert_eq!(options.render_mode, RenderMode::Spacious);
+        assert_eq!(options.output, Some("compact"));
+
+        assert!(parse(&["list", "--dense"]).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use super::ConnectionTestFuture;
+use crate::config::Config;
+use anyhow::{Context, Result};
+use std::future::Future;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+/// Default timeout for `integrations test`
+pub const DEFAULT_TIMEOUT_SECS: u64 = 15;
+
+const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Await `future`, animating a spinner followed by `message` on stderr while
+/// it runs. Nothing is drawn when stderr isn't a terminal.
+pub async fn with_spinner<F: Future>(message: &str, future: F) -> F::Output {
+    if !console::user_attended_stderr() {
+        return future.await;
+    }
+    tokio::pin!(future);
+    let mut ticker = tokio::time::interval(Duration::from_millis(80));
+    let mut stderr = std::io::stderr();
+    for frame in SPINNER_FRAMES.iter().cycle() {
+        tokio::select! {
+            output = &mut future => {
+                let _ = write!(stderr, "\r\x1b[2K");
+                let _ = stderr.flush();
+                return output;
+            }
+            _ = ticker.tick() => {
+                let _ = write!(stderr, "\r  {frame} {message}");
+                let _ = stderr.flush();
+            }
+        }
+    }
+    unreachable!("SPINNER_FRAMES cycles forever")
+}
+
+/// Send `request` and return its round-trip time, failing on non-2xx.
+async fn timed(request: reqwest::RequestBuilder) -> Result<Duration> {
+    let started = Instant::now();
+    request
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        // The URL can carry a bot token; keep it out of the report.
+        .map_err(reqwest::Error::without_url)?;
+    Ok(started.elapsed())
+}
+
+/// `inline`, else `config.api_key` when `provider` is the default provider,
+/// else the provider's environment variables.
+fn provider_credential(config: &Config, provider: &str, inline: Option<&str>) -> Option<String> {
+    let configured = config
+        .api_key
+        .as_deref()
+        .filter(|_| config.default_provider.as_deref() == Some(provider));
+    crate::providers::resolve_provider_credential(provider, inline.or(configured))
+}
+
+fn base_url(config: &Config, name: &str) -> Result<String> {
+    config
+        .integration_base_url(name)
+        .with_context(|| format!("no {name} API base URL"))
+}
+
+/// OpenRouter: list models with the given key.
+pub(super) fn openrouter(config: &Config, inline: Option<&str>) -> ConnectionTestFuture {
+    let base = base_url(config, "OpenRouter");
+    let key = provider_credential(config, "openrouter", inline);
+    Box::pin(async move {
+        let key = key.context("no OpenRouter API key; pass --api-key or set OPENROUTER_API_KEY")?;
+        timed(
+            crate::config::build_runtime_proxy_client("integrations.test.openrouter")
+                .get(format!("{}/models", base?))
+                .bearer_auth(key),
+        )
+        .await
+    })
+}
+
+/// Anthropic: list models with an API key or a setup token.
+pub(super) fn anthropic(config: &Config, inline: Option<&str>) -> ConnectionTestFuture {
+    let base = base_url(config, "Anthropic");
+    let key = provider_credential(config, "anthropic", inline);
+    Box::pin(async move {
+        let key = key.context(
+            "no Anthropic credential; pass --api-key or set ANTHROPIC_API_KEY / ANTHROPIC_OAUTH_TOKEN",
+        )?;
+        let request = crate::config::build_runtime_proxy_client("integrations.test.anthropic")
+            .get(format!("{}/v1/models", base?))
+            .header("anthropic-version", "2023-06-01");
+        let request = if key.starts_with("sk-ant-oat01-") {
+            request
+                .bearer_auth(key)
+                .header("anthropic-beta", "oauth-2025-04-20")
+        } else {
+            request.header("x-api-key", key)
+        };
+        timed(request).await
+    })
+}
+
+/// OpenAI: list models with the given key.
+pub(super) fn openai(config: &Config, inline: Option<&str>) -> ConnectionTestFuture {
+    let base = base_url(config, "OpenAI");
+    let key = provider_credential(config, "openai", inline);
+    Box::pin(async move {
+        let key = key.context("no OpenAI API key; pass --api-key or set OPENAI_API_KEY")?;
+        timed(
+            crate::config::build_runtime_proxy_client("integrations.test.openai")
+                .get(format!("{}/models", base?))
+                .bearer_auth(key),
+        )
+        .await
+    })
+}
+
+/// Ollama: list local models; a key is only sent when one is available.
+pub(super) fn ollama(config: &Config, inline: Option<&str>) -> ConnectionTestFuture {
+    let base = match (config.integrations.base_url("Ollama"), &config.api_url) {
+        (None, Some(api_url)) => Ok(api_url.trim_end_matches('/').to_string()),
+        _ => base_url(config, "Ollama"),
+    };
+    let key = provider_credential(config, "ollama", inline);
+    Box::pin(async move {
+        let request = crate::config::build_runtime_proxy_client("integrations.test.ollama")
+            .get(format!("{}/api/tags", base?));
+        timed(match key {
+            Some(key) => request.bearer_auth(key),
+            None => request,
+        })
+        .await
+    })
+}
+
+/// Telegram: `getMe` with the inline or configured bot token. Sending a real
+/// message would need a chat id, so this only proves the token works.
+pub(super) fn telegram(config: &Config, inline: Option<&str>) -> ConnectionTestFuture {
+    let telegram = config.channels_config.telegram.as_ref();
+    let token = inline
+        .map(str::to_string)
+        .or_else(|| telegram.map(|t| t.bot_token.clone()));
+    let base = telegram
+        .and_then(|t| t.base_url.as_deref())
+        .map(|url| Ok(url.trim_end_matches('/').to_string()))
+        .unwrap_or_else(|| base_url(config, "Telegram"));
+    Box::pin(async move {
+        let token = token.context("no Telegram bot token; pass --api-key or configure it")?;
+        timed(
+            crate::config::build_runtime_proxy_client("integrations.test.telegram")
+                .get(format!("{}/bot{token}/getMe", base?)),
+        )
+        .await
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn config_pointing_at(name: &str, server: &MockServer) -> Config {
+        let mut config = Config::default();
+        config.integrations.overrides.insert(
+            name.to_string(),
+            crate::config::schema::IntegrationOverrideConfig {
+                base_url: Some(server.uri()),
+            },
+        );
+        config
+    }
+
+    #[tokio::test]
+    async fn openai_test_sends_inline_key_to_models_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .and(header("authorization", "Bearer sk-inline"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"data":[]}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = config_pointing_at("OpenAI", &server);
+        openai(&config, Some("sk-inline")).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn telegram_test_fails_on_rejected_token_without_leaking_it() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/botbad-token/getMe"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let config = config_pointing_at("Telegram", &server);
+        let err = telegram(&config, Some("bad-token")).await.unwrap_err();
+        assert!(!err.to_string().contains("bad-token"));
+    }
+
+    #[tokio::test]
+    async fn with_spinner_returns_the_future_output() {
+        assert_eq!(with_spinner("Testing", async { 7 }).await, 7);
+    }
+}
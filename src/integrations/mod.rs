@@ -1,10 +1,41 @@
+pub mod builder;
+pub mod compare;
+pub mod completions;
+pub mod coverage;
+pub mod doctor;
+pub mod error;
+pub mod html;
+pub mod list;
+pub mod live_test;
+pub mod presets;
+pub mod prometheus;
 pub mod registry;
+pub mod runner;
+pub mod search;
+pub mod slack;
+pub mod state;
+pub mod tls;
+pub mod transfer;
+
+pub use error::IntegrationError;
+
+use list::{compact_row, search_output_format, verbose_details, OutputFormat, OUTPUT_FORMAT_ENV};
 
 use crate::config::Config;
-use anyhow::Result;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::io::Write as _;
+use std::panic::AssertUnwindSafe;
+use std::path::Path;
+use std::pin::Pin;
+use std::time::Duration;
 
-/// Integration status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+/// Integration status, ordered by how configured an entry is:
+/// `Active > Degraded > AuthExpired > Available > ComingSoon`. Sort
+/// descending to list configured integrations first.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum IntegrationStatus {
     /// Fully implemented and ready to use
     Available,
@@ -12,10 +43,68 @@ pub enum IntegrationStatus {
     Active,
     /// Planned but not yet implemented
     ComingSoon,
+    /// Configured but unhealthy; carries a short reason
+    Degraded(String),
+    /// Configured, but the credential was rejected (revoked token, expired
+    /// key); `since` is when that was first seen, if known
+    AuthExpired {
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    },
+}
+
+impl IntegrationStatus {
+    /// Set up by the user: `Active`, `Degraded` or `AuthExpired`. Every
+    /// "N active" count (list summary, setup completeness, stats) uses this,
+    /// so an unhealthy integration still counts as configured.
+    pub fn is_configured(&self) -> bool {
+        matches!(
+            self,
+            Self::Active | Self::Degraded(_) | Self::AuthExpired { .. }
+        )
+    }
+
+    /// Position in the `Ord` ranking; the derive would follow declaration
+    /// order, which puts `Available` above `Active`.
+    fn rank(&self) -> u8 {
+        match self {
+            Self::ComingSoon => 0,
+            Self::Available => 1,
+            Self::AuthExpired { .. } => 2,
+            Self::Degraded(_) => 3,
+            Self::Active => 4,
+        }
+    }
+}
+
+impl Ord for IntegrationStatus {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::Degraded(a), Self::Degraded(b)) => a.cmp(b),
+            (Self::AuthExpired { since: a }, Self::AuthExpired { since: b }) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for IntegrationStatus {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for IntegrationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Degraded(reason) if !reason.is_empty() => write!(f, "Degraded ({reason})"),
+            other => f.pad(status_group_label(other)),
+        }
+    }
 }
 
 /// Integration category
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
 pub enum IntegrationCategory {
     Chat,
     AiModel,
@@ -43,6 +132,21 @@ impl IntegrationCategory {
         }
     }
 
+    /// Emoji shown next to the label in `list` headings and `info`
+    pub fn icon(self) -> &'static str {
+        match self {
+            Self::Chat => "💬",
+            Self::AiModel => "🤖",
+            Self::Productivity => "📋",
+            Self::MusicAudio => "🎵",
+            Self::SmartHome => "🏠",
+            Self::ToolsAutomation => "🔧",
+            Self::MediaCreative => "🎨",
+            Self::Social => "🌐",
+            Self::Platform => "🖥️",
+        }
+    }
+
     pub fn all() -> &'static [Self] {
         &[
             Self::Chat,
@@ -58,404 +162,2824 @@ impl IntegrationCategory {
     }
 }
 
-/// A registered integration
+impl std::fmt::Display for IntegrationCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.label())
+    }
+}
+
+/// How production-ready an integration is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum Maturity {
+    Stable,
+    Beta,
+    Experimental,
+}
+
+impl Maturity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Stable => "Stable",
+            Self::Beta => "Beta",
+            Self::Experimental => "Experimental",
+        }
+    }
+}
+
+/// How the underlying service is paid for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum PricingModel {
+    Free,
+    /// Paid plans with a usable free tier
+    Freemium,
+    PaidOnly,
+    /// Open-source software you run yourself
+    OpenSource,
+}
+
+impl PricingModel {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Free => "Free",
+            Self::Freemium => "Freemium",
+            Self::PaidOnly => "Paid only",
+            Self::OpenSource => "Open source",
+        }
+    }
+}
+
+/// How an integration authenticates against its service
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum AuthType {
+    ApiKey,
+    OAuth2,
+    BearerToken,
+    BasicAuth,
+    /// Local or anonymous; nothing to authenticate
+    None,
+}
+
+impl AuthType {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ApiKey => "API Key",
+            Self::OAuth2 => "OAuth2",
+            Self::BearerToken => "Bearer Token",
+            Self::BasicAuth => "Basic Auth",
+            Self::None => "None",
+        }
+    }
+}
+
+/// Operating system an integration runs on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum Platform {
+    MacOs,
+    Linux,
+    Windows,
+    Any,
+}
+
+impl Platform {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::MacOs => "macOS",
+            Self::Linux => "Linux",
+            Self::Windows => "Windows",
+            Self::Any => "Any",
+        }
+    }
+
+    /// The OS this binary was compiled for; `None` on other targets.
+    pub fn current() -> Option<Self> {
+        if cfg!(target_os = "macos") {
+            Some(Self::MacOs)
+        } else if cfg!(target_os = "linux") {
+            Some(Self::Linux)
+        } else if cfg!(target_os = "windows") {
+            Some(Self::Windows)
+        } else {
+            None
+        }
+    }
+}
+
+/// Kind of message a channel integration can send and receive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum MessageType {
+    Text,
+    Image,
+    Audio,
+    Video,
+    File,
+    ReactionEmoji,
+    Sticker,
+}
+
+impl MessageType {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Text => "Text",
+            Self::Image => "Image",
+            Self::Audio => "Audio",
+            Self::Video => "Video",
+            Self::File => "File",
+            Self::ReactionEmoji => "Reaction Emoji",
+            Self::Sticker => "Sticker",
+        }
+    }
+
+    /// Type of an inbound message: `Image` when it carries `[IMAGE:...]`
+    /// markers, `Text` otherwise.
+    pub fn of(content: &str) -> Self {
+        if crate::multimodal::parse_image_markers(content).1.is_empty() {
+            Self::Text
+        } else {
+            Self::Image
+        }
+    }
+}
+
+/// An environment variable an integration reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct EnvVarSpec {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Whether the integration cannot work without it
+    pub required: bool,
+}
+
+/// A registered integration; build one with [`IntegrationEntry::builder`]
+#[derive(Clone)]
 pub struct IntegrationEntry {
     pub name: &'static str,
     pub description: &'static str,
     pub category: IntegrationCategory,
-    pub status_fn: fn(&Config) -> IntegrationStatus,
+    /// License of the underlying service (e.g. "Proprietary", "Apache 2.0")
+    pub license: &'static str,
+    pub maturity: Maturity,
+    pub pricing_model: PricingModel,
+    /// Operating systems the integration works on; `[Platform::Any]` for most
+    pub platform_support: &'static [Platform],
+    /// Longest text message the platform accepts, in characters. Channels
+    /// split longer replies before sending.
+    pub max_message_size: Option<usize>,
+    /// Message types the channel handles; `[MessageType::Text]` by default.
+    /// Filtered by `list --supports`; the channel dispatcher only logs a
+    /// warning for unlisted types.
+    pub supported_message_types: &'static [MessageType],
+    /// Binaries that must be on `PATH` for the integration to work
+    pub system_dependencies: &'static [&'static str],
+    /// One-liner that exercises the integration once it is configured
+    pub example_command: Option<&'static str>,
+    /// Default API endpoint; `[integrations.<name>] base_url` overrides it
+    /// (see `Config::integration_base_url`). Empty if not applicable.
+    pub api_base_url: &'static str,
+    /// Runs entirely on the user's own infrastructure (Ollama, Cron, ...)
+    /// rather than calling a third-party API; filtered by `list --self-hosted`
+    pub self_hosted: bool,
+    /// Credential scheme shown in `info`; filtered by `list --auth-type`
+    pub authentication_type: AuthType,
+    /// Where the service stores user data: "US", "EU", "Self-hosted",
+    /// "Local", "Unknown", ...; filtered by `list --data-residency`
+    pub data_residency: &'static str,
+    pub environment_variables: &'static [EnvVarSpec],
+    /// Dotted `config.toml` keys (e.g. `channels_config.telegram.bot_token`)
+    /// the integration can't run without
+    pub required_config_keys: &'static [&'static str],
+    /// Dotted `config.toml` keys that tune the integration
+    pub optional_config_keys: &'static [&'static str],
+    /// Freeform labels such as "requires-api-key", "self-hosted" or
+    /// "offline", matched by `list --tag` and by search
+    pub tags: &'static [&'static str],
+    /// Where to report problems; empty for community-maintained entries
+    pub contact_email: &'static str,
+    /// Release notes of the underlying service's API, for tracking breaking
+    /// changes; empty when the service publishes none
+    pub changelog_url: &'static str,
+    /// Module implementing the integration (e.g. `channels::telegram`),
+    /// looked up in the coverage report by `info`; `None` when no single
+    /// module does
+    pub source_module: Option<&'static str>,
+    /// Live preflight run by `integrations doctor` for `Active` entries
+    pub health_check_fn: Option<HealthCheckFn>,
+    /// On-demand check run by `integrations test`, whatever the status
+    pub test_fn: Option<ConnectionTestFn>,
+    /// Must not panic: report a failed check as `Degraded` instead, since
+    /// release builds abort on panic
+    pub status_fn: StatusFn,
+}
+
+impl IntegrationEntry {
+    /// Current status under `config`
+    pub fn status(&self, config: &Config) -> IntegrationStatus {
+        self.status_fn.call(config)
+    }
+}
+
+/// Status function of a runtime-registered integration
+pub type DynStatusFn = dyn Fn(&Config) -> IntegrationStatus + Send + Sync;
+
+/// How an entry works out its status
+#[derive(Clone, Copy)]
+pub enum StatusFn {
+    /// Built-in entries, set with [`builder::IntegrationEntryBuilder::status_fn`]
+    Static(fn(&Config) -> IntegrationStatus),
+    /// Entries added with [`registry::register`], whose closure may capture
+    Dyn(&'static DynStatusFn),
+}
+
+impl StatusFn {
+    pub fn call(self, config: &Config) -> IntegrationStatus {
+        match self {
+            Self::Static(status_fn) => status_fn(config),
+            Self::Dyn(status_fn) => status_fn(config),
+        }
+    }
+}
+
+/// An integration added at runtime with [`registry::register`], e.g. by a
+/// plugin crate at startup. Same fields as [`IntegrationEntry`], except that
+/// the name and description are owned and the status function may capture.
+/// Serializes every field except the callbacks.
+#[derive(serde::Serialize)]
+pub struct IntegrationEntryOwned {
+    pub name: String,
+    pub description: String,
+    pub category: IntegrationCategory,
+    pub license: &'static str,
+    pub maturity: Maturity,
+    pub pricing_model: PricingModel,
+    pub platform_support: &'static [Platform],
+    pub max_message_size: Option<usize>,
+    pub supported_message_types: &'static [MessageType],
+    pub system_dependencies: &'static [&'static str],
+    pub example_command: Option<&'static str>,
+    pub api_base_url: &'static str,
+    pub self_hosted: bool,
+    pub authentication_type: AuthType,
+    pub data_residency: &'static str,
+    pub environment_variables: &'static [EnvVarSpec],
+    pub required_config_keys: &'static [&'static str],
+    pub optional_config_keys: &'static [&'static str],
+    pub tags: &'static [&'static str],
+    pub contact_email: &'static str,
+    pub changelog_url: &'static str,
+    pub source_module: Option<&'static str>,
+    #[serde(skip)]
+    pub health_check_fn: Option<HealthCheckFn>,
+    #[serde(skip)]
+    pub test_fn: Option<ConnectionTestFn>,
+    #[serde(skip)]
+    pub status_fn: Box<DynStatusFn>,
+}
+
+impl IntegrationEntryOwned {
+    /// An entry with the same defaults as [`IntegrationEntry::builder`];
+    /// set any other field directly before registering it. `status_fn` must
+    /// not panic; return `Degraded` for a failed check.
+    pub fn new(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        category: IntegrationCategory,
+        license: &'static str,
+        pricing_model: PricingModel,
+        status_fn: impl Fn(&Config) -> IntegrationStatus + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            category,
+            license,
+            maturity: Maturity::Stable,
+            pricing_model,
+            platform_support: &[Platform::Any],
+            max_message_size: None,
+            supported_message_types: &[MessageType::Text],
+            system_dependencies: &[],
+            example_command: None,
+            api_base_url: "",
+            self_hosted: false,
+            authentication_type: AuthType::None,
+            data_residency: "Unknown",
+            environment_variables: &[],
+            required_config_keys: &[],
+            optional_config_keys: &[],
+            tags: &[],
+            contact_email: "",
+            changelog_url: "",
+            source_module: None,
+            health_check_fn: None,
+            test_fn: None,
+            status_fn: Box::new(status_fn),
+        }
+    }
 }
 
+impl From<&'static IntegrationEntry> for IntegrationEntryOwned {
+    fn from(entry: &'static IntegrationEntry) -> Self {
+        Self {
+            name: entry.name.to_string(),
+            description: entry.description.to_string(),
+            category: entry.category,
+            license: entry.license,
+            maturity: entry.maturity,
+            pricing_model: entry.pricing_model,
+            platform_support: entry.platform_support,
+            max_message_size: entry.max_message_size,
+            supported_message_types: entry.supported_message_types,
+            system_dependencies: entry.system_dependencies,
+            example_command: entry.example_command,
+            api_base_url: entry.api_base_url,
+            self_hosted: entry.self_hosted,
+            authentication_type: entry.authentication_type,
+            data_residency: entry.data_residency,
+            environment_variables: entry.environment_variables,
+            required_config_keys: entry.required_config_keys,
+            optional_config_keys: entry.optional_config_keys,
+            tags: entry.tags,
+            contact_email: entry.contact_email,
+            changelog_url: entry.changelog_url,
+            source_module: entry.source_module,
+            health_check_fn: entry.health_check_fn,
+            test_fn: entry.test_fn,
+            status_fn: Box::new(|config| entry.status(config)),
+        }
+    }
+}
+
+/// Future returned by a [`HealthCheckFn`]
+pub type HealthCheckFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
+
+/// Connectivity check (HTTP ping, token validation, ...) for one integration
+pub type HealthCheckFn = fn(&Config) -> HealthCheckFuture;
+
+/// Future returned by a [`ConnectionTestFn`]; resolves to the request's
+/// round-trip time
+pub type ConnectionTestFuture = Pin<Box<dyn Future<Output = Result<Duration>> + Send>>;
+
+/// Minimal live API request (list models, `getMe`, ...) for one integration.
+/// The `Option<&str>` is an inline credential that wins over config and env.
+pub type ConnectionTestFn = fn(&Config, Option<&str>) -> ConnectionTestFuture;
+
 /// Handle the `integrations` CLI command
-pub fn handle_command(command: crate::IntegrationCommands, config: &Config) -> Result<()> {
+pub async fn handle_command(command: crate::IntegrationCommands, config: &Config) -> Result<()> {
     match command {
-        crate::IntegrationCommands::List { category, status } => {
-            list_integrations(config, category.as_deref(), status.as_deref())
+        crate::IntegrationCommands::List(args) => list::run(config, &args).await,
+        crate::IntegrationCommands::Search {
+            query,
+            category,
+            category_exclude,
+            status,
+            match_whole_word,
+            min_results,
+            json,
+            compact,
+            verbose,
+            explain,
+            fuzzy,
+            fuzzy_threshold,
+            save,
+            load,
+        } => {
+            let store = presets::PresetStore::for_config(config);
+            let preset = match load {
+                Some(name) => store
+                    .load(&name)?
+                    .ok_or_else(|| IntegrationError::UnknownPreset(name.clone()))?,
+                None => presets::SearchPreset {
+                    query: query.ok_or(IntegrationError::MissingQuery)?,
+                    category,
+                    category_exclude,
+                    status,
+                },
+            };
+            validate_search_filters(&preset)?;
+            if let Some(name) = save {
+                store.save(&name, &preset)?;
+                println!("  Saved search preset '{name}'.");
+            }
+            print_search(
+                config,
+                &SearchOptions {
+                    query: &preset.query,
+                    category: preset.category.as_deref(),
+                    category_exclude: preset.category_exclude.as_deref(),
+                    status: preset.status.as_deref(),
+                    match_whole_word,
+                    min_results,
+                    output: if json {
+                        OutputFormat::Json
+                    } else if compact {
+                        OutputFormat::Compact
+                    } else if verbose {
+                        OutputFormat::Verbose
+                    } else {
+                        search_output_format(std::env::var(OUTPUT_FORMAT_ENV).ok().as_deref())
+                    },
+                    explain,
+                    fuzzy,
+                    fuzzy_threshold: fuzzy_threshold.as_deref(),
+                },
+            )
+        }
+        crate::IntegrationCommands::Info {
+            name,
+            compare,
+            output,
+        } => match (compare, output.as_deref().map(str::to_lowercase).as_deref()) {
+            (Some(other), _) => compare_integrations(config, &name, &other),
+            (None, None | Some("text")) => show_integration_info(config, &name),
+            (None, Some("html")) => {
+                let entry = find_integration(&name)?;
+                print!(
+                    "{}",
+                    html::render_entry(entry, resolve_status(entry, config))
+                );
+                Ok(())
+            }
+            (None, Some(_)) => Err(IntegrationError::invalid_option(
+                "output format",
+                &output.unwrap_or_default(),
+                &["text", "html"],
+            )
+            .into()),
+        },
+        crate::IntegrationCommands::Doctor {
+            timeout_secs,
+            slack_notify,
+            report_html,
+            on_success_run,
+        } => {
+            let timeout =
+                Duration::from_secs(timeout_secs.unwrap_or(doctor::DEFAULT_TIMEOUT_SECS).max(1));
+            let entries = registry::all_integrations();
+            let reports = doctor::run(&entries, config, timeout).await;
+            if let Err(e) = state::StateStore::open(&state::StateStore::path_for_config(config))
+                .and_then(|store| doctor::record(&store, &reports))
+            {
+                tracing::warn!("Failed to record integration check history: {e:#}");
+            }
+            if let Some(path) = report_html {
+                write_atomically(
+                    &path,
+                    &html::render_check_report(&reports, chrono::Utc::now()),
+                )
+                .with_context(|| format!("Failed to write HTML report to {}", path.display()))?;
+            }
+            if reports.is_empty() {
+                println!("No active integrations to check. Run `zeroclaw onboard` first.");
+                return Ok(());
+            }
+
+            println!("🩺 ZeroClaw Integrations Doctor");
+            println!();
+            print!("{}", doctor::render_table(&reports));
+            println!();
+
+            if let Some(webhook_url) = slack_notify {
+                slack::send_check_summary(&webhook_url, &reports)
+                    .await
+                    .context("Failed to send the Slack check summary")?;
+            }
+
+            let failed = reports.iter().filter(|r| r.failed()).count();
+            if failed > 0 {
+                return Err(IntegrationError::ChecksFailed {
+                    failed,
+                    total: reports.len(),
+                }
+                .into());
+            }
+            match on_success_run {
+                Some(cmd) => runner::on_success(&cmd),
+                None => Ok(()),
+            }
+        }
+        crate::IntegrationCommands::Test {
+            name,
+            api_key,
+            timeout_secs,
+        } => {
+            let timeout = Duration::from_secs(
+                timeout_secs
+                    .unwrap_or(live_test::DEFAULT_TIMEOUT_SECS)
+                    .max(1),
+            );
+            test_integration(config, &name, api_key.as_deref(), timeout).await
+        }
+        crate::IntegrationCommands::Validate { name } => validate_integration(config, &name),
+        crate::IntegrationCommands::Stats { json } => stats_integrations(config, json),
+        crate::IntegrationCommands::Pin { name } => set_pinned(config, &name, true).await,
+        crate::IntegrationCommands::Unpin { name } => set_pinned(config, &name, false).await,
+        crate::IntegrationCommands::Export { format, output } => {
+            transfer::check_format(format.as_deref())?;
+            let exported = transfer::export(&registry::all_integrations(), config);
+            let json = serde_json::to_string_pretty(&exported)? + "\n";
+            match output {
+                Some(path) => {
+                    write_atomically(&path, &json)?;
+                    println!(
+                        "  Exported {} integrations to {}.",
+                        exported.len(),
+                        path.display()
+                    );
+                    Ok(())
+                }
+                None => {
+                    print!("{json}");
+                    Ok(())
+                }
+            }
+        }
+        crate::IntegrationCommands::Completions { shell } => {
+            print!(
+                "{}",
+                completions::script(shell, &registry::all_integrations())?
+            );
+            Ok(())
+        }
+        crate::IntegrationCommands::Import { file, dry_run } => {
+            Box::pin(import_integrations(config, &file, dry_run)).await
+        }
+    }
+}
+
+/// `integrations import`: print the diff between `file` and the live config,
+/// then (unless `dry_run`) back up `config.toml`, unset the keys the file
+/// doesn't have and save.
+async fn import_integrations(config: &Config, file: &Path, dry_run: bool) -> Result<()> {
+    let exported = transfer::read(file)?;
+    let changes = transfer::plan(&exported, config)?;
+    print!("{}", transfer::render_diff(&changes));
+    if dry_run {
+        return Ok(());
+    }
+    if changes.iter().all(|c| c.keys_to_unset.is_empty()) {
+        return Ok(());
+    }
+    if let Some(backup) = transfer::backup(&config.config_path, chrono::Utc::now())? {
+        println!("  Backed up config to {}.", backup.display());
+    }
+    transfer::apply(config, &changes)?.save().await?;
+    println!("  Applied {}.", file.display());
+    Ok(())
+}
+
+/// Add `name` to (or remove it from) `[integrations.pinned]` and save the
+/// config. Unpinning matches case-insensitively so names that have since
+/// left the registry can still be removed.
+async fn set_pinned(config: &Config, name: &str, pinned: bool) -> Result<()> {
+    let mut updated = config.clone();
+    let names = &mut updated.integrations.pinned.names;
+
+    if pinned {
+        let entry = find_integration(name)?;
+        if names.iter().any(|n| n.eq_ignore_ascii_case(entry.name)) {
+            println!("  {} is already pinned.", entry.name);
+            return Ok(());
+        }
+        names.push(entry.name.to_string());
+        updated.save().await?;
+        println!("  ★ Pinned {}.", entry.name);
+    } else {
+        let before = names.len();
+        names.retain(|n| !n.eq_ignore_ascii_case(name));
+        if names.len() == before {
+            println!("  {name} is not pinned.");
+            return Ok(());
         }
-        crate::IntegrationCommands::Search { query } => search_integrations(config, &query),
-        crate::IntegrationCommands::Info { name } => show_integration_info(config, &name),
+        updated.save().await?;
+        println!("  Unpinned {name}.");
     }
+    Ok(())
 }
 
-fn status_icon(status: IntegrationStatus) -> &'static str {
+fn status_icon(status: &IntegrationStatus) -> &'static str {
     match status {
         IntegrationStatus::Active => "✅",
         IntegrationStatus::Available => "⚪",
         IntegrationStatus::ComingSoon => "🔜",
+        IntegrationStatus::Degraded(_) => "⚠️",
+        IntegrationStatus::AuthExpired { .. } => "🔴",
+    }
+}
+
+/// Heading an entry is listed under when grouping by status.
+fn status_group_label(status: &IntegrationStatus) -> &'static str {
+    match status {
+        IntegrationStatus::Active => "Active",
+        IntegrationStatus::Degraded(_) => "Degraded",
+        IntegrationStatus::AuthExpired { .. } => "Auth Expired",
+        IntegrationStatus::Available => "Available",
+        IntegrationStatus::ComingSoon => "Coming Soon",
+    }
+}
+
+/// `Expired 3 days ago` for an `AuthExpired` status that knows when the
+/// credential stopped working.
+fn auth_expired_age(
+    status: &IntegrationStatus,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<String> {
+    let IntegrationStatus::AuthExpired { since: Some(since) } = status else {
+        return None;
+    };
+    let age = (now - *since).to_std().unwrap_or_default();
+    Some(format!("Expired {}", format_age(age)))
+}
+
+/// Coarse human-readable age, e.g. "just now", "1 minute ago", "3 days ago".
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    let (amount, unit) = match secs {
+        0..=9 => return "just now".to_string(),
+        10..=59 => (secs, "second"),
+        60..=3_599 => (secs / 60, "minute"),
+        3_600..=86_399 => (secs / 3_600, "hour"),
+        _ => (secs / 86_400, "day"),
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+    format!("{amount} {unit}{plural} ago")
+}
+
+/// Evaluate an entry's status function, reporting a panic as `Degraded`.
+///
+/// This is a debug and test build aid, not isolation: release and dist
+/// builds use `panic = "abort"`, so a panicking `status_fn` still takes the
+/// process down there. Status functions return `Degraded` themselves.
+fn resolve_status(entry: &IntegrationEntry, config: &Config) -> IntegrationStatus {
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| entry.status(config)));
+
+    match result {
+        Ok(status) => status,
+        Err(_) => {
+            eprintln!(
+                "  {} status check for {} panicked; reporting it as degraded",
+                console::style("⚠").yellow(),
+                entry.name
+            );
+            IntegrationStatus::Degraded("status_fn panicked".into())
+        }
+    }
+}
+
+impl std::str::FromStr for IntegrationCategory {
+    type Err = IntegrationError;
+
+    /// Parse a category name or alias (e.g. `ai`, `smart-home`), case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "chat" => Ok(Self::Chat),
+            "ai" | "model" | "models" | "ai-model" | "ai-models" => Ok(Self::AiModel),
+            "productivity" => Ok(Self::Productivity),
+            "music" | "audio" | "music-audio" => Ok(Self::MusicAudio),
+            "smart-home" | "smarthome" | "home" => Ok(Self::SmartHome),
+            "tools" | "automation" | "tools-automation" => Ok(Self::ToolsAutomation),
+            "media" | "creative" | "media-creative" => Ok(Self::MediaCreative),
+            "social" => Ok(Self::Social),
+            "platform" | "platforms" => Ok(Self::Platform),
+            _ => Err(IntegrationError::invalid_category(s)),
+        }
+    }
+}
+
+impl std::str::FromStr for IntegrationStatus {
+    type Err = IntegrationError;
+
+    /// Parse a status name or alias (e.g. `soon`, `unhealthy`), case-insensitively.
+    /// `degraded` parses to `Degraded` with an empty reason, `auth-expired`
+    /// to `AuthExpired` without a timestamp.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(Self::Active),
+            "available" => Ok(Self::Available),
+            "coming-soon" | "comingsoon" | "soon" => Ok(Self::ComingSoon),
+            "degraded" | "unhealthy" => Ok(Self::Degraded(String::new())),
+            "auth-expired" | "authexpired" | "expired" | "invalid" => {
+                Ok(Self::AuthExpired { since: None })
+            }
+            _ => Err(IntegrationError::invalid_status(s)),
+        }
+    }
+}
+
+/// Parse a category name or alias via `IntegrationCategory`'s `FromStr` impl.
+pub fn parse_category_filter(input: &str) -> Option<IntegrationCategory> {
+    input.parse().ok()
+}
+
+/// Parse a status name or alias via `IntegrationStatus`'s `FromStr` impl.
+pub fn parse_status_filter(input: &str) -> Option<IntegrationStatus> {
+    input.parse().ok()
+}
+
+/// Parse an optional, comma-separated `--category` argument, rejecting
+/// unknown values. Empty means no category filter.
+fn category_filter_arg(input: Option<&str>) -> Result<Vec<IntegrationCategory>> {
+    let mut categories = input
+        .into_iter()
+        .flat_map(|input| input.split(','))
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<IntegrationCategory>, _>>()?;
+    categories.sort_unstable();
+    categories.dedup();
+    Ok(categories)
+}
+
+/// Parse `--category-order`: the listed categories first, in the given
+/// order, then the rest in `IntegrationCategory::all()` order.
+fn category_order_arg(input: Option<&str>) -> Result<Vec<IntegrationCategory>> {
+    let mut order: Vec<IntegrationCategory> = Vec::new();
+    for token in input
+        .into_iter()
+        .flat_map(|input| input.split(','))
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+    {
+        let category = parse_category_filter(token)
+            .ok_or_else(|| IntegrationError::invalid_category(token))?;
+        if !order.contains(&category) {
+            order.push(category);
+        }
+    }
+    for category in IntegrationCategory::all() {
+        if !order.contains(category) {
+            order.push(*category);
+        }
     }
+    Ok(order)
+}
+
+/// Whether `category` passes a `--category` filter; an empty filter matches
+/// everything.
+fn category_matches(filters: &[IntegrationCategory], category: IntegrationCategory) -> bool {
+    filters.is_empty() || filters.contains(&category)
+}
+
+/// Whether `status` passes a `--status` filter. `Degraded` matches
+/// regardless of its reason, `AuthExpired` regardless of its timestamp.
+fn status_matches(status: &IntegrationStatus, filter: &IntegrationStatus) -> bool {
+    std::mem::discriminant(status) == std::mem::discriminant(filter)
+}
+
+/// Parse an optional `--status` argument, rejecting unknown values.
+fn status_filter_arg(input: Option<&str>) -> Result<Option<IntegrationStatus>> {
+    Ok(input.map(str::parse).transpose()?)
+}
+
+/// Whether `entry` carries `tag`, ignoring case.
+fn has_tag(entry: &IntegrationEntry, tag: &str) -> bool {
+    entry.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+}
+
+/// OSI-approved licenses recognised by `is_open_source_license`.
+const OPEN_SOURCE_LICENSES: &[&str] = &[
+    "MIT",
+    "Apache 2.0",
+    "BSD 2-Clause",
+    "BSD 3-Clause",
+    "GPL v2",
+    "GPL v3",
+    "LGPL v3",
+    "AGPL v3",
+    "MPL 2.0",
+    "ISC",
+];
+
+/// Whether `license` is OSI-approved. Dual licenses ("MIT / Apache 2.0")
+/// count as open source when every alternative is.
+fn is_open_source_license(license: &str) -> bool {
+    license.split(" / ").all(|part| {
+        OPEN_SOURCE_LICENSES
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(part.trim()))
+    })
 }
 
-fn parse_category_filter(input: &str) -> Option<IntegrationCategory> {
+/// Returns `Some(true)` for open-source, `Some(false)` for proprietary.
+fn parse_license_filter(input: &str) -> Option<bool> {
     match input.to_lowercase().as_str() {
-        "chat" => Some(IntegrationCategory::Chat),
-        "ai" | "model" | "models" | "ai-model" | "ai-models" => Some(IntegrationCategory::AiModel),
-        "productivity" => Some(IntegrationCategory::Productivity),
-        "music" | "audio" | "music-audio" => Some(IntegrationCategory::MusicAudio),
-        "smart-home" | "smarthome" | "home" => Some(IntegrationCategory::SmartHome),
-        "tools" | "automation" | "tools-automation" => Some(IntegrationCategory::ToolsAutomation),
-        "media" | "creative" | "media-creative" => Some(IntegrationCategory::MediaCreative),
-        "social" => Some(IntegrationCategory::Social),
-        "platform" | "platforms" => Some(IntegrationCategory::Platform),
+        "open-source" | "opensource" | "oss" => Some(true),
+        "proprietary" => Some(false),
         _ => None,
     }
 }
 
-fn parse_status_filter(input: &str) -> Option<IntegrationStatus> {
+fn parse_maturity_filter(input: &str) -> Option<Maturity> {
     match input.to_lowercase().as_str() {
-        "active" => Some(IntegrationStatus::Active),
-        "available" => Some(IntegrationStatus::Available),
-        "coming-soon" | "comingsoon" | "soon" => Some(IntegrationStatus::ComingSoon),
+        "stable" => Some(Maturity::Stable),
+        "beta" => Some(Maturity::Beta),
+        "experimental" | "exp" => Some(Maturity::Experimental),
         _ => None,
     }
 }
 
-fn list_integrations(
-    config: &Config,
-    category_filter: Option<&str>,
-    status_filter: Option<&str>,
-) -> Result<()> {
-    let entries = registry::all_integrations();
+/// Parse an optional `--filter-maturity` argument, rejecting unknown values.
+fn maturity_filter_arg(input: Option<&str>) -> Result<Option<Maturity>> {
+    let Some(input) = input else {
+        return Ok(None);
+    };
+    match parse_maturity_filter(input) {
+        Some(maturity) => Ok(Some(maturity)),
+        None => Err(IntegrationError::invalid_option(
+            "maturity",
+            input,
+            &["stable", "beta", "experimental"],
+        )
+        .into()),
+    }
+}
 
-    let cat_filter = category_filter.map(parse_category_filter);
-    if let Some(None) = cat_filter.as_ref() {
-        anyhow::bail!(
-            "Unknown category: '{}'. Valid: chat, ai, productivity, music, smart-home, tools, media, social, platform",
-            category_filter.unwrap_or_default()
-        );
+fn parse_pricing_filter(input: &str) -> Option<PricingModel> {
+    match input.to_lowercase().as_str() {
+        "free" => Some(PricingModel::Free),
+        "freemium" => Some(PricingModel::Freemium),
+        "paid" | "paid-only" | "paidonly" => Some(PricingModel::PaidOnly),
+        "open-source" | "opensource" | "oss" => Some(PricingModel::OpenSource),
+        _ => None,
     }
-    let cat_filter = cat_filter.flatten();
+}
 
-    let stat_filter = status_filter.map(parse_status_filter);
-    if let Some(None) = stat_filter.as_ref() {
-        anyhow::bail!(
-            "Unknown status: '{}'. Valid: active, available, coming-soon",
-            status_filter.unwrap_or_default()
-        );
+/// Whether `entry` works on the OS this binary was built for.
+fn supports_current_platform(entry: &IntegrationEntry) -> bool {
+    entry
+        .platform_support
+        .iter()
+        .any(|p| *p == Platform::Any || Some(*p) == Platform::current())
+}
+
+/// Whether the integration behind `channel` (e.g. "telegram") lists
+/// `message_type`. Channels without a registry entry accept everything.
+/// Advisory: the registry metadata can be incomplete.
+pub fn channel_supports(channel: &str, message_type: MessageType) -> bool {
+    registry::find(channel).is_none_or(|e| e.supported_message_types.contains(&message_type))
+}
+
+fn parse_message_type(input: &str) -> Option<MessageType> {
+    match input.to_lowercase().as_str() {
+        "text" => Some(MessageType::Text),
+        "image" => Some(MessageType::Image),
+        "audio" => Some(MessageType::Audio),
+        "video" => Some(MessageType::Video),
+        "file" => Some(MessageType::File),
+        "reaction" | "reaction-emoji" => Some(MessageType::ReactionEmoji),
+        "sticker" => Some(MessageType::Sticker),
+        _ => None,
     }
-    let stat_filter = stat_filter.flatten();
+}
 
-    let mut count = 0usize;
-    for cat in IntegrationCategory::all() {
-        if let Some(ref cf) = cat_filter {
-            if *cf != *cat {
-                continue;
-            }
-        }
+/// Parse an optional `--supports` argument, rejecting unknown values.
+fn message_type_filter_arg(input: Option<&str>) -> Result<Option<MessageType>> {
+    let Some(input) = input else {
+        return Ok(None);
+    };
+    match parse_message_type(input) {
+        Some(message_type) => Ok(Some(message_type)),
+        None => Err(IntegrationError::invalid_option(
+            "message type",
+            input,
+            &[
+                "text", "image", "audio", "video", "file", "reaction", "sticker",
+            ],
+        )
+        .into()),
+    }
+}
 
-        let cat_entries: Vec<_> = entries
-            .iter()
-            .filter(|e| e.category == *cat)
-            .filter(|e| {
-                if let Some(ref sf) = stat_filter {
-                    (e.status_fn)(config) == *sf
-                } else {
-                    true
-                }
-            })
-            .collect();
+/// Parse an optional `--pricing` argument, rejecting unknown values.
+fn pricing_filter_arg(input: Option<&str>) -> Result<Option<PricingModel>> {
+    let Some(input) = input else {
+        return Ok(None);
+    };
+    match parse_pricing_filter(input) {
+        Some(pricing) => Ok(Some(pricing)),
+        None => Err(IntegrationError::invalid_option(
+            "pricing model",
+            input,
+            &["free", "freemium", "paid-only", "open-source"],
+        )
+        .into()),
+    }
+}
 
-        if cat_entries.is_empty() {
-            continue;
-        }
+fn parse_auth_type_filter(input: &str) -> Option<AuthType> {
+    match input.to_lowercase().as_str() {
+        "api-key" | "apikey" | "api_key" => Some(AuthType::ApiKey),
+        "oauth2" | "oauth" => Some(AuthType::OAuth2),
+        "bearer" | "bearer-token" | "token" => Some(AuthType::BearerToken),
+        "basic" | "basic-auth" => Some(AuthType::BasicAuth),
+        "none" => Some(AuthType::None),
+        _ => None,
+    }
+}
 
-        println!();
-        println!("  {}", console::style(cat.label()).bold().underlined());
-        for entry in &cat_entries {
-            let status = (entry.status_fn)(config);
-            println!(
-                "    {} {:<20} {}",
-                status_icon(status),
-                entry.name,
-                console::style(entry.description).dim()
-            );
-            count += 1;
-        }
+/// Parse an optional `--auth-type` argument, rejecting unknown values.
+fn auth_type_filter_arg(input: Option<&str>) -> Result<Option<AuthType>> {
+    let Some(input) = input else {
+        return Ok(None);
+    };
+    match parse_auth_type_filter(input) {
+        Some(auth) => Ok(Some(auth)),
+        None => Err(IntegrationError::invalid_option(
+            "auth type",
+            input,
+            &["api-key", "oauth2", "bearer", "basic", "none"],
+        )
+        .into()),
     }
+}
 
-    println!();
-    println!("  {} integration(s) shown.", count);
-    println!();
-    Ok(())
+/// Comma-separated platform labels, e.g. "macOS" or "macOS, Linux".
+fn platform_label(platforms: &[Platform]) -> String {
+    platforms
+        .iter()
+        .map(|p| p.label())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `Pricing:` line for `integrations info`.
+fn pricing_line(pricing: PricingModel) -> String {
+    match pricing {
+        PricingModel::Freemium => format!("  Pricing:  {} (free tier available)", pricing.label()),
+        _ => format!("  Pricing:  {}", pricing.label()),
+    }
 }
 
-fn search_integrations(config: &Config, query: &str) -> Result<()> {
-    let entries = registry::all_integrations();
-    let query_lower = query.to_lowercase();
+/// Every registry entry in one of `category_filters` (all categories if
+/// empty) whose resolved status matches `status`. A `Degraded` filter
+/// matches any reason.
+pub fn list_integrations(
+    config: &Config,
+    category_filters: &[IntegrationCategory],
+    status: Option<IntegrationStatus>,
+) -> Result<Vec<IntegrationSummary>> {
+    Ok(registry::all_integrations()
+        .iter()
+        .filter(|e| category_matches(category_filters, e.category))
+        .map(|e| (e, resolve_status(e, config)))
+        .filter(|(_, s)| status.as_ref().is_none_or(|sf| status_matches(s, sf)))
+        .map(|(e, s)| IntegrationSummary::new(e, s))
+        .collect())
+}
+
+/// Write `contents` to a temp file next to `path`, then rename it over
+/// `path`, so watchers never observe a partially written file.
+fn write_atomically(path: &Path, contents: &str) -> Result<()> {
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let mut file = tempfile::NamedTempFile::new_in(dir)
+        .with_context(|| format!("Failed to create temp file in {}", dir.display()))?;
+    file.write_all(contents.as_bytes())?;
+    file.persist(path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// An entry with its resolved status, as returned by [`list_integrations`]
+/// and [`search_integrations`]. Serializes with display labels for `--json`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct IntegrationSummary {
+    pub name: String,
+    pub description: String,
+    #[serde(serialize_with = "serialize_category_label")]
+    pub category: IntegrationCategory,
+    #[serde(serialize_with = "serialize_status_label")]
+    pub status: IntegrationStatus,
+    /// Search relevance: 1.0 for exact matches, the trigram similarity for
+    /// fuzzy ones. `None` outside search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relevance: Option<f64>,
+}
+
+impl IntegrationSummary {
+    fn new(entry: &IntegrationEntry, status: IntegrationStatus) -> Self {
+        Self {
+            name: entry.name.to_string(),
+            description: entry.description.to_string(),
+            category: entry.category,
+            status,
+            relevance: None,
+        }
+    }
+
+    fn with_relevance(self, relevance: f64) -> Self {
+        Self {
+            relevance: Some(relevance),
+            ..self
+        }
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // signature fixed by serde
+fn serialize_category_label<S: serde::Serializer>(
+    category: &IntegrationCategory,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_str(category)
+}
+
+fn serialize_status_label<S: serde::Serializer>(
+    status: &IntegrationStatus,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_str(status)
+}
+
+/// Per-category `[active, available, coming_soon]` counts. Degraded and
+/// auth-expired entries are configured, so they count as active.
+fn stats_counts(
+    entries: &[&IntegrationEntry],
+    config: &Config,
+) -> BTreeMap<IntegrationCategory, [usize; 3]> {
+    let mut counts: BTreeMap<IntegrationCategory, [usize; 3]> = BTreeMap::new();
+    for entry in entries {
+        let column = match resolve_status(entry, config) {
+            status if status.is_configured() => 0,
+            IntegrationStatus::ComingSoon => 2,
+            _ => 1,
+        };
+        counts.entry(entry.category).or_default()[column] += 1;
+    }
+    counts
+}
+
+/// Category × status table with a grand-total row.
+fn render_stats_table(counts: &BTreeMap<IntegrationCategory, [usize; 3]>) -> String {
+    fn row(out: &mut String, label: &str, [active, available, coming_soon]: [usize; 3]) {
+        let total = active + available + coming_soon;
+        let _ = writeln!(
+            out,
+            "  {label:<18} {active:>6} {available:>9} {coming_soon:>11} {total:>5}"
+        );
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "  {:<18} {:>6} {:>9} {:>11} {:>5}",
+        "Category", "Active", "Available", "Coming Soon", "Total"
+    );
+    let mut totals = [0; 3];
+    for (category, counts) in counts {
+        row(&mut out, category.label(), *counts);
+        for (total, count) in totals.iter_mut().zip(counts) {
+            *total += count;
+        }
+    }
+    row(&mut out, "Total", totals);
+    out
+}
+
+fn stats_json(counts: &BTreeMap<IntegrationCategory, [usize; 3]>) -> serde_json::Value {
+    counts
+        .iter()
+        .map(|(category, [active, available, coming_soon])| {
+            serde_json::json!({
+                "category": category.label(),
+                "active": active,
+                "available": available,
+                "coming_soon": coming_soon,
+            })
+        })
+        .collect()
+}
+
+/// `integrations stats`: how many integrations are active, available or
+/// coming soon in each category.
+fn stats_integrations(config: &Config, json: bool) -> Result<()> {
+    let counts = stats_counts(&registry::all_integrations(), config);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats_json(&counts))?);
+    } else {
+        print!("{}", render_stats_table(&counts));
+    }
+    Ok(())
+}
+
+/// Relevance of an exact search hit: `+3` for a name match, `+3` for a tag
+/// match and `+1` for a description match, so a tag hit ranks with a name
+/// hit and above a description-only hit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct SearchScore(u8);
+
+impl SearchScore {
+    const NAME: u8 = 3;
+    const TAG: u8 = 3;
+    const DESCRIPTION: u8 = 1;
+
+    fn of(entry: &IntegrationEntry, is_match: impl Fn(&str) -> bool) -> Self {
+        let mut score = 0;
+        if is_match(entry.name) {
+            score += Self::NAME;
+        }
+        if entry.tags.iter().any(|t| is_match(t)) {
+            score += Self::TAG;
+        }
+        if is_match(entry.description) {
+            score += Self::DESCRIPTION;
+        }
+        Self(score)
+    }
+
+    /// Score with a plain case-insensitive substring match.
+    fn for_query(entry: &IntegrationEntry, query: &str) -> Self {
+        let query_lower = query.to_lowercase();
+        Self::of(entry, |text| text.to_lowercase().contains(&query_lower))
+    }
+}
+
+/// Entries whose name, description or tags match `query` (case-insensitive),
+/// highest [`SearchScore`] first; ties keep registry order.
+///
+/// With `match_whole_word`, the query must appear as a whole word, so "ai"
+/// matches "AI image generation" but not "Email".
+fn matching_entries<'a>(
+    entries: &[&'a IntegrationEntry],
+    query: &str,
+    match_whole_word: bool,
+) -> Result<Vec<&'a IntegrationEntry>> {
+    let query_lower = query.to_lowercase();
+    let whole_word = if match_whole_word {
+        let pattern = format!(r"\b{}\b", regex::escape(query));
+        Some(
+            regex::RegexBuilder::new(&pattern)
+                .case_insensitive(true)
+                .build()?,
+        )
+    } else {
+        None
+    };
+
+    let is_match = |text: &str| match &whole_word {
+        Some(re) => re.is_match(text),
+        None => text.to_lowercase().contains(&query_lower),
+    };
+
+    let mut scored: Vec<_> = entries
+        .iter()
+        .map(|&e| (e, SearchScore::of(e, is_match)))
+        .filter(|(_, score)| *score > SearchScore::default())
+        .collect();
+    scored.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+    Ok(scored.into_iter().map(|(e, _)| e).collect())
+}
+
+/// Query and filters for `integrations search`
+#[derive(Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct SearchOptions<'a> {
+    query: &'a str,
+    category: Option<&'a str>,
+    category_exclude: Option<&'a str>,
+    status: Option<&'a str>,
+    match_whole_word: bool,
+    min_results: Option<usize>,
+    output: OutputFormat,
+    explain: bool,
+    fuzzy: bool,
+    fuzzy_threshold: Option<&'a str>,
+}
+
+/// Parse an optional `--fuzzy-threshold` (0.0–1.0), defaulting to
+/// [`search::DEFAULT_THRESHOLD`].
+fn fuzzy_threshold_arg(input: Option<&str>) -> Result<f64> {
+    let Some(input) = input else {
+        return Ok(search::DEFAULT_THRESHOLD);
+    };
+    match input.trim().parse::<f64>() {
+        Ok(threshold) if (0.0..=1.0).contains(&threshold) => Ok(threshold),
+        _ => Err(IntegrationError::invalid_option(
+            "fuzzy threshold",
+            input,
+            &["a number from 0.0 to 1.0"],
+        )
+        .into()),
+    }
+}
+
+/// Exact matches (relevance 1.0) followed by trigram matches that aren't
+/// already exact, best first. Trigram matching only runs with `fuzzy` or
+/// when nothing matched exactly.
+fn ranked_entries<'a>(
+    entries: &[&'a IntegrationEntry],
+    query: &str,
+    match_whole_word: bool,
+    fuzzy: bool,
+    threshold: f64,
+) -> Result<Vec<(&'a IntegrationEntry, f64)>> {
+    let mut ranked: Vec<_> = matching_entries(entries, query, match_whole_word)?
+        .into_iter()
+        .map(|entry| (entry, 1.0))
+        .collect();
+    if fuzzy || ranked.is_empty() {
+        for (entry, score) in search::TrigramIndex::new(entries).search(query, threshold) {
+            if !ranked.iter().any(|(e, _)| std::ptr::eq(*e, entry)) {
+                ranked.push((entry, score));
+            }
+        }
+    }
+    Ok(ranked)
+}
+
+/// Reject an unknown `--category`, `--category-exclude` or `--status` before
+/// the preset is saved or searched.
+fn validate_search_filters(preset: &presets::SearchPreset) -> Result<()> {
+    category_filter_arg(preset.category.as_deref())?;
+    category_filter_arg(preset.category_exclude.as_deref())?;
+    status_filter_arg(preset.status.as_deref())?;
+    Ok(())
+}
+
+/// Entries matching the search query and every filter in `options`, with
+/// their resolved status and relevance.
+fn search_matches<'a>(
+    entries: &[&'a IntegrationEntry],
+    config: &Config,
+    options: &SearchOptions<'_>,
+) -> Result<Vec<(&'a IntegrationEntry, IntegrationStatus, f64)>> {
+    let cat_filter = category_filter_arg(options.category)?;
+    let cat_exclude = category_filter_arg(options.category_exclude)?;
+    let stat_filter = status_filter_arg(options.status)?;
+
+    Ok(ranked_entries(
+        entries,
+        options.query,
+        options.match_whole_word,
+        options.fuzzy,
+        fuzzy_threshold_arg(options.fuzzy_threshold)?,
+    )?
+    .into_iter()
+    .filter(|(e, _)| category_matches(&cat_filter, e.category))
+    .filter(|(e, _)| !cat_exclude.contains(&e.category))
+    .map(|(e, score)| (e, resolve_status(e, config), score))
+    .filter(|(_, status, _)| {
+        stat_filter
+            .as_ref()
+            .is_none_or(|sf| status_matches(status, sf))
+    })
+    .collect())
+}
+
+/// Entries whose name, description or tags contain `query` (case-insensitive),
+/// narrowed by `category_filters` and `status` like [`list_integrations`].
+/// Falls back to trigram matching when nothing contains `query`; each
+/// summary carries its `relevance`. Exact matches are sorted by
+/// [`SearchScore`], then most configured first (see [`IntegrationStatus`]'s
+/// `Ord`), then by name.
+pub fn search_integrations(
+    config: &Config,
+    query: &str,
+    category_filters: &[IntegrationCategory],
+    status: Option<IntegrationStatus>,
+) -> Result<Vec<IntegrationSummary>> {
+    let entries = registry::all_integrations();
+    let mut results: Vec<_> =
+        ranked_entries(&entries, query, false, false, search::DEFAULT_THRESHOLD)?
+            .into_iter()
+            .filter(|(e, _)| category_matches(category_filters, e.category))
+            .map(|(e, score)| (e, resolve_status(e, config), score))
+            .filter(|(_, s, _)| status.as_ref().is_none_or(|sf| status_matches(s, sf)))
+            .collect();
+    // Fuzzy fallback hits keep their relevance ranking.
+    if results.iter().all(|(_, _, relevance)| *relevance == 1.0) {
+        results.sort_by_cached_key(|(e, s, _)| {
+            (
+                std::cmp::Reverse(SearchScore::for_query(e, query)),
+                std::cmp::Reverse(s.clone()),
+                e.name.to_lowercase(),
+            )
+        });
+    }
+    Ok(results
+        .into_iter()
+        .map(|(e, s, score)| IntegrationSummary::new(e, s).with_relevance(score))
+        .collect())
+}
+
+fn print_search(config: &Config, options: &SearchOptions<'_>) -> Result<()> {
+    let query = options.query;
+    let entries = registry::all_integrations();
+    let matches = search_matches(&entries, config, options)?;
+
+    if options.output == OutputFormat::Json {
+        let summaries: Vec<_> = matches
+            .iter()
+            .map(|(entry, status, score)| {
+                IntegrationSummary::new(entry, status.clone()).with_relevance(*score)
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&summaries)?);
+        return check_min_results(matches.len(), options.min_results);
+    }
+
+    if options.output == OutputFormat::Compact {
+        for (entry, status, _) in &matches {
+            println!("{}", compact_row(entry, status));
+        }
+        return check_min_results(matches.len(), options.min_results);
+    }
+
+    if matches.is_empty() {
+        println!();
+        println!("  No integrations matching '{query}'.");
+        println!();
+        return check_min_results(0, options.min_results);
+    }
+
+    println!();
+    if !options.fuzzy && matches.iter().all(|(_, _, score)| *score < 1.0) {
+        println!("  No exact matches for '{query}'; showing similar names:");
+    }
+    let config_json = (options.output == OutputFormat::Verbose)
+        .then(|| serde_json::to_value(config).unwrap_or_default());
+    for (entry, status, score) in &matches {
+        let explanation = match (options.explain, *score < 1.0) {
+            (true, true) => format!(
+                " {}",
+                console::style(format!("[matched: fuzzy, relevance {score:.2}]")).dim()
+            ),
+            (true, false) => format!(" {}", console::style(explain_match(entry, query)).dim()),
+            (false, true) => format!(" {}", console::style(format!("({score:.2})")).dim()),
+            (false, false) => String::new(),
+        };
+        println!(
+            "    {} {:<20} {} — {}{explanation}",
+            status_icon(status),
+            entry.name,
+            console::style(entry.category).dim(),
+            entry.description,
+        );
+        if let Some(config_json) = &config_json {
+            println!("{}", verbose_details(entry, config_json));
+        }
+    }
+    println!();
+    println!("  {} result(s) for '{query}'.", matches.len());
+    println!();
+    check_min_results(matches.len(), options.min_results)
+}
+
+/// Why `entry` matched `query`, e.g. `[matched: name contains "bot"]` or
+/// `[matched: tag "offline"]`. Fields are checked in the order search uses.
+fn explain_match(entry: &IntegrationEntry, query: &str) -> String {
+    let query_lower = query.to_lowercase();
+    let contains = |text: &str| text.to_lowercase().contains(&query_lower);
+    if contains(entry.name) {
+        format!("[matched: name contains \"{query}\"]")
+    } else if contains(entry.description) {
+        format!("[matched: description contains \"{query}\"]")
+    } else if let Some(tag) = entry.tags.iter().find(|t| contains(t)) {
+        format!("[matched: tag \"{tag}\"]")
+    } else {
+        "[no match]".to_string()
+    }
+}
+
+/// Fail `integrations search --min-results <n>` when fewer than `n` matched,
+/// so CI scripts get a non-zero exit code.
+fn check_min_results(found: usize, min_results: Option<usize>) -> Result<()> {
+    match min_results {
+        Some(min) if found < min => Err(IntegrationError::TooFewResults { min, found }.into()),
+        _ => Ok(()),
+    }
+}
+
+/// `ollama (found: /usr/local/bin/ollama)` or `ollama (❌ not found)`.
+fn dependency_status(binary: &str) -> String {
+    match which::which(binary) {
+        Ok(path) => format!("{binary} (found: {})", path.display()),
+        Err(_) => format!("{binary} (❌ not found)"),
+    }
+}
+
+fn quick_test_line(entry: &IntegrationEntry) -> Option<String> {
+    entry
+        .example_command
+        .map(|command| format!("  Quick test: $ {command}"))
+}
+
+fn report_issues_line(entry: &IntegrationEntry) -> Option<String> {
+    (!entry.contact_email.is_empty()).then(|| format!("  Report issues: {}", entry.contact_email))
+}
+
+fn changelog_line(entry: &IntegrationEntry) -> Option<String> {
+    (!entry.changelog_url.is_empty())
+        .then(|| format!("  Service changelog: {}", entry.changelog_url))
+}
+
+/// Whether the dotted `key` resolves to a non-empty value in `config_json`
+/// (the serialized config).
+fn config_key_is_set(config_json: &serde_json::Value, key: &str) -> bool {
+    let value = key
+        .split('.')
+        .try_fold(config_json, |value, part| value.get(part));
+    match value {
+        None | Some(serde_json::Value::Null) => false,
+        Some(serde_json::Value::String(s)) => !s.trim().is_empty(),
+        Some(_) => true,
+    }
+}
+
+/// `entry.required_config_keys` that aren't set in `config`.
+fn missing_config_keys(entry: &IntegrationEntry, config: &Config) -> Vec<&'static str> {
+    let config_json = serde_json::to_value(config).unwrap_or_default();
+    entry
+        .required_config_keys
+        .iter()
+        .copied()
+        .filter(|key| !config_key_is_set(&config_json, key))
+        .collect()
+}
+
+/// One `Configuration` row: set keys in green, missing required keys in red.
+fn config_key_line(key: &str, is_set: bool, required: bool) -> String {
+    match (is_set, required) {
+        (true, true) => console::style(format!("{key:<40} ✅ set (required)"))
+            .green()
+            .to_string(),
+        (true, false) => console::style(format!("{key:<40} ✅ set"))
+            .green()
+            .to_string(),
+        (false, true) => console::style(format!("{key:<40} ❌ missing (required)"))
+            .red()
+            .to_string(),
+        (false, false) => format!("{key:<40} ⚪ not set"),
+    }
+}
+
+/// `integrations test`: run `name`'s connectivity test under a spinner and
+/// report the round-trip time, failing if the request fails or times out.
+async fn test_integration(
+    config: &Config,
+    name: &str,
+    api_key: Option<&str>,
+    timeout: Duration,
+) -> Result<()> {
+    let entry = find_integration(name)?;
+    let test = entry
+        .test_fn
+        .ok_or_else(|| IntegrationError::NoConnectionTest(entry.name.to_string()))?;
+
+    let message = format!("Testing {}…", entry.name);
+    let outcome = live_test::with_spinner(
+        &message,
+        tokio::time::timeout(timeout, test(config, api_key)),
+    )
+    .await;
+    let reason = match outcome {
+        Ok(Ok(latency)) => {
+            println!(
+                "  ✅ {} responded in {} ms",
+                entry.name,
+                latency.as_millis()
+            );
+            return Ok(());
+        }
+        Ok(Err(e)) => crate::providers::sanitize_api_error(&e.to_string()),
+        Err(_) => format!("timed out after {timeout:?}"),
+    };
+    println!("  ❌ {}: {reason}", entry.name);
+    Err(IntegrationError::ConnectionTestFailed {
+        name: entry.name.to_string(),
+        reason,
+    }
+    .into())
+}
+
+/// `integrations validate`: fail with the missing keys if any required config
+/// key of `name` is unset.
+fn validate_integration(config: &Config, name: &str) -> Result<()> {
+    let entry = find_integration(name)?;
+    let missing = missing_config_keys(entry, config);
+    if !missing.is_empty() {
+        return Err(IntegrationError::MissingConfigKeys {
+            name: entry.name.to_string(),
+            missing: missing.into_iter().map(str::to_string).collect(),
+        }
+        .into());
+    }
+    println!("  ✅ {}: all required config keys are set", entry.name);
+    Ok(())
+}
+
+/// `Latency p95` from the `integrations doctor` history, if the entry has
+/// been checked. Never creates the store.
+fn latency_line(config: &Config, name: &str) -> Option<String> {
+    let path = state::StateStore::path_for_config(config);
+    if !path.exists() {
+        return None;
+    }
+    let percentiles = state::StateStore::open(&path)
+        .ok()?
+        .check_percentiles(name)?;
+    Some(format!("  Latency p95: {}ms", percentiles.p95.as_millis()))
+}
+
+fn coverage_line(percent: Option<f64>) -> String {
+    match percent {
+        Some(percent) => format!("  Test coverage: {percent:.1}%"),
+        None => "  (coverage data unavailable)".to_string(),
+    }
+}
+
+/// One `Environment Variables` row; a required variable that isn't set is
+/// flagged with ❌.
+fn env_var_line(spec: &EnvVarSpec, is_set: bool) -> String {
+    let state = match (is_set, spec.required) {
+        (true, _) => "✅ set",
+        (false, true) => "❌ not set (required)",
+        (false, false) => "⚪ not set",
+    };
+    format!("{:<24} {state} — {}", spec.name, spec.description)
+}
+
+/// Case-insensitive lookup via [`registry::name_index`]; unknown names get
+/// an `UnknownIntegration` error with close-match suggestions.
+fn find_integration(name: &str) -> Result<&'static IntegrationEntry, IntegrationError> {
+    registry::find(name).ok_or_else(|| {
+        IntegrationError::unknown_integration(
+            name,
+            registry::all_integrations().iter().map(|e| e.name),
+        )
+    })
+}
+
+fn compare_integrations(config: &Config, name: &str, other: &str) -> Result<()> {
+    let a = find_integration(name)?;
+    let b = find_integration(other)?;
+
+    println!();
+    print!("{}", compare::side_by_side(a, b, config));
+    println!();
+    Ok(())
+}
+
+/// Print the `integrations info` block for `name` to stdout.
+pub fn show_integration_info(config: &Config, name: &str) -> Result<()> {
+    let entry = find_integration(name)?;
+
+    let status = resolve_status(entry, config);
+    let icon = status_icon(&status);
+
+    println!();
+    println!(
+        "  {} {} — {}",
+        icon,
+        console::style(entry.name).white().bold(),
+        entry.description
+    );
+    println!("  Category: {} {}", entry.category.icon(), entry.category);
+    match auth_expired_age(&status, chrono::Utc::now()) {
+        Some(age) => println!("  Status:   {status} ({age})"),
+        None => println!("  Status:   {status}"),
+    }
+    println!("  License:  {}", entry.license);
+    println!("{}", pricing_line(entry.pricing_model));
+    println!("  Platform: {}", platform_label(entry.platform_support));
+    println!(
+        "  Self-hosted: {}",
+        if entry.self_hosted { "yes" } else { "no" }
+    );
+    println!("  Auth: {}", entry.authentication_type.label());
+    println!("  Data residency: {}", entry.data_residency);
+    if let Some(limit) = entry.max_message_size {
+        println!("  Max message: {limit} chars");
+    }
+    if entry.category == IntegrationCategory::Chat {
+        let types: Vec<_> = entry
+            .supported_message_types
+            .iter()
+            .map(|t| t.label())
+            .collect();
+        println!("  Message types: {}", types.join(", "));
+    }
+    for binary in entry.system_dependencies {
+        println!("  Requires: {}", dependency_status(binary));
+    }
+    if let Some(line) = quick_test_line(entry) {
+        println!("{line}");
+    }
+    if let Some(line) = report_issues_line(entry) {
+        println!("{line}");
+    }
+    if let Some(line) = changelog_line(entry) {
+        println!("{line}");
+    }
+    if let Some(line) = latency_line(config, entry.name) {
+        println!("{line}");
+    }
+    if let Some(module) = entry.source_module {
+        println!("{}", coverage_line(coverage::module_coverage(module)));
+    }
+    if !entry.required_config_keys.is_empty() || !entry.optional_config_keys.is_empty() {
+        let config_json = serde_json::to_value(config).unwrap_or_default();
+        println!("  Configuration:");
+        let keys = entry
+            .required_config_keys
+            .iter()
+            .map(|key| (key, true))
+            .chain(entry.optional_config_keys.iter().map(|key| (key, false)));
+        for (key, required) in keys {
+            let is_set = config_key_is_set(&config_json, key);
+            println!("    {}", config_key_line(key, is_set, required));
+        }
+    }
+    if !entry.environment_variables.is_empty() {
+        println!("  Environment Variables:");
+        for spec in entry.environment_variables {
+            let is_set = std::env::var(spec.name).is_ok_and(|v| !v.trim().is_empty());
+            println!("    {}", env_var_line(spec, is_set));
+        }
+    }
+    match entry.maturity {
+        Maturity::Stable => {}
+        Maturity::Beta => println!(
+            "  Maturity: {}",
+            console::style(format!("🚧 {}", entry.maturity.label())).yellow()
+        ),
+        Maturity::Experimental => println!(
+            "  Maturity: {}",
+            console::style(format!("🧪 {}", entry.maturity.label())).yellow()
+        ),
+    }
+    println!();
+
+    // Show setup hints based on integration
+    match entry.name {
+        "Telegram" => {
+            if let Some(bot_id) = config
+                .channels_config
+                .telegram
+                .as_ref()
+                .and_then(|tg| crate::channels::telegram::parse_bot_id(&tg.bot_token))
+            {
+                println!("  Bot ID: {bot_id}");
+                println!();
+            }
+            println!("  Setup:");
+            println!("    1. Message @BotFather on Telegram");
+            println!("    2. Create a bot and copy the token");
+            println!("    3. Run: zeroclaw onboard --channels-only");
+            println!("    4. Start: zeroclaw channel start");
+        }
+        "Discord" => {
+            println!("  Setup:");
+            println!("    1. Go to https://discord.com/developers/applications");
+            println!("    2. Create app → Bot → Copy token");
+            println!("    3. Enable MESSAGE CONTENT intent");
+            println!("    4. Run: zeroclaw onboard --channels-only");
+        }
+        "Slack" => {
+            println!("  Setup:");
+            println!("    1. Go to https://api.slack.com/apps");
+            println!("    2. Create app → Bot Token Scopes → Install");
+            println!("    3. Run: zeroclaw onboard --channels-only");
+        }
+        "OpenRouter" => {
+            println!("  Setup:");
+            println!("    1. Get API key at https://openrouter.ai/keys");
+            println!("    2. Run: zeroclaw onboard");
+            println!("    Access 200+ models with one key.");
+        }
+        "Ollama" => {
+            println!("  Setup:");
+            println!("    1. Install: brew install ollama");
+            println!("    2. Pull a model: ollama pull llama3");
+            println!("    3. Set provider to 'ollama' in config.toml");
+        }
+        "iMessage" => {
+            println!("  Setup:");
+            println!("    Uses AppleScript bridge to send/receive iMessages.");
+            println!("    Requires Full Disk Access in System Settings → Privacy.");
+        }
+        "GitHub" => {
+            println!("  Setup:");
+            println!("    Coming soon: there are no [integrations.github] settings yet.");
+        }
+        "Browser" => {
+            println!("  Built-in:");
+            println!("    ZeroClaw can control Chrome/Chromium for web tasks.");
+            println!("    Uses headless browser automation.");
+        }
+        "Cron" => {
+            println!("  Built-in:");
+            println!("    Schedule tasks in ~/.zeroclaw/workspace/cron/");
+            println!("    Run: zeroclaw cron list");
+        }
+        "Webhooks" => {
+            println!("  Built-in:");
+            println!("    HTTP endpoint for external triggers.");
+            println!("    Run: zeroclaw gateway");
+        }
+        _ => {
+            if status == IntegrationStatus::ComingSoon {
+                println!("  This integration is planned. Stay tuned!");
+                println!("  Track progress: https://github.com/theonlyhennygod/zeroclaw");
+            }
+        }
+    }
+
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `integrations list` args with the CLI defaults.
+    fn list_args() -> crate::IntegrationListArgs {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            list: crate::IntegrationListArgs,
+        }
+        <Cli as clap::Parser>::parse_from(["list"]).list
+    }
+
+    #[test]
+    fn integration_category_all_includes_every_variant_once() {
+        let all = IntegrationCategory::all();
+        assert_eq!(all.len(), 9);
+
+        let labels: Vec<&str> = all.iter().map(|cat| cat.label()).collect();
+        assert!(all.iter().all(|cat| !cat.icon().is_empty()));
+        assert!(labels.contains(&"Chat Providers"));
+        assert!(labels.contains(&"AI Models"));
+        assert!(labels.contains(&"Productivity"));
+        assert!(labels.contains(&"Music & Audio"));
+        assert!(labels.contains(&"Smart Home"));
+        assert!(labels.contains(&"Tools & Automation"));
+        assert!(labels.contains(&"Media & Creative"));
+        assert!(labels.contains(&"Social"));
+        assert!(labels.contains(&"Platforms"));
+    }
+
+    pub(super) fn entry_with_status(
+        status_fn: fn(&Config) -> IntegrationStatus,
+    ) -> IntegrationEntry {
+        IntegrationEntry::builder()
+            .name("Test")
+            .description("Test entry")
+            .category(IntegrationCategory::ToolsAutomation)
+            .license("MIT")
+            .pricing_model(PricingModel::OpenSource)
+            .status_fn(status_fn)
+            .build()
+    }
+
+    #[test]
+    fn channel_supports_checks_the_matching_integration() {
+        assert!(channel_supports("signal", MessageType::Image));
+        assert!(!channel_supports("email", MessageType::Sticker));
+        assert!(channel_supports("nostr", MessageType::Text));
+        assert!(!channel_supports("nostr", MessageType::Image));
+        assert!(channel_supports("unregistered-channel", MessageType::Video));
+        assert_eq!(
+            MessageType::of("look [IMAGE:data:image/png;base64,abcd]"),
+            MessageType::Image
+        );
+        assert_eq!(MessageType::of("hello"), MessageType::Text);
+    }
+
+    #[test]
+    fn resolve_status_reports_panicking_status_fn_as_degraded() {
+        let config = Config::default();
+        let entry = IntegrationEntry::builder()
+            .name("Panicky")
+            .description("Always panics")
+            .category(IntegrationCategory::ToolsAutomation)
+            .license("MIT")
+            .pricing_model(PricingModel::OpenSource)
+            .status_fn(|_| panic!("boom"))
+            .build();
+
+        let status = resolve_status(&entry, &config);
+        assert_eq!(
+            status,
+            IntegrationStatus::Degraded("status_fn panicked".into())
+        );
+        assert_eq!(status_icon(&status), "⚠️");
+    }
+
+    #[tokio::test]
+    async fn handle_command_info_is_case_insensitive_for_known_integrations() {
+        let config = Config::default();
+        let first_name = registry::all_integrations()
+            .first()
+            .expect("registry should define at least one integration")
+            .name
+            .to_lowercase();
+
+        let result = handle_command(
+            crate::IntegrationCommands::Info {
+                name: first_name,
+                compare: None,
+                output: None,
+            },
+            &config,
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn handle_command_info_returns_error_for_unknown_integration() {
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::Info {
+                name: "definitely-not-a-real-integration".into(),
+                compare: None,
+                output: None,
+            },
+            &config,
+        )
+        .await;
+
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Unknown integration"));
+    }
+
+    #[tokio::test]
+    async fn info_unknown_integration_suggests_close_match() {
+        let config = Config::default();
+        let err = handle_command(
+            crate::IntegrationCommands::Info {
+                name: "telgram".into(),
+                compare: None,
+                output: None,
+            },
+            &config,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<IntegrationError>(),
+            Some(IntegrationError::UnknownIntegration { suggestions, .. })
+                if suggestions == &["Telegram"]
+        ));
+        assert!(err.to_string().contains("Did you mean 'Telegram'?"));
+    }
+
+    #[tokio::test]
+    async fn info_html_output_succeeds_and_rejects_unknown_formats() {
+        let config = Config::default();
+        let info = |output: &str| crate::IntegrationCommands::Info {
+            name: "Shell".into(),
+            compare: None,
+            output: Some(output.into()),
+        };
+
+        assert!(handle_command(info("html"), &config).await.is_ok());
+        let err = handle_command(info("pdf"), &config).await.unwrap_err();
+        assert!(err.to_string().contains("Unknown output format"));
+    }
+
+    #[tokio::test]
+    async fn info_compare_with_unknown_integration_fails() {
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::Info {
+                name: "Shell".into(),
+                compare: Some("definitely-not-a-real-integration".into()),
+                output: None,
+            },
+            &config,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown integration"));
+    }
+
+    #[tokio::test]
+    async fn list_all_integrations_succeeds() {
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::List(crate::IntegrationListArgs { ..list_args() }),
+            &config,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn list_with_category_filter_succeeds() {
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                category: Some("chat".into()),
+                ..list_args()
+            }),
+            &config,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn list_with_status_filter_succeeds() {
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                status: Some("available".into()),
+                ..list_args()
+            }),
+            &config,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn list_with_invalid_category_fails() {
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                category: Some("nonexistent".into()),
+                ..list_args()
+            }),
+            &config,
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Unknown category"));
+    }
+
+    #[tokio::test]
+    async fn list_with_invalid_status_fails() {
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                status: Some("bogus".into()),
+                ..list_args()
+            }),
+            &config,
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Unknown status"));
+    }
+
+    #[test]
+    fn format_age_picks_largest_whole_unit() {
+        assert_eq!(format_age(Duration::from_secs(3)), "just now");
+        assert_eq!(format_age(Duration::from_secs(45)), "45 seconds ago");
+        assert_eq!(format_age(Duration::from_secs(60)), "1 minute ago");
+        assert_eq!(
+            format_age(Duration::from_secs(2 * 3_600 + 59)),
+            "2 hours ago"
+        );
+        assert_eq!(format_age(Duration::from_secs(3 * 86_400)), "3 days ago");
+    }
+
+    #[tokio::test]
+    async fn list_with_invalid_license_fails() {
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                license: Some("shareware".into()),
+                ..list_args()
+            }),
+            &config,
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown license filter"));
+    }
+
+    #[tokio::test]
+    async fn list_with_max_entries_shows_exactly_that_many() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("integrations.txt");
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                output_file: Some(path.clone()),
+                max_entries: Some(3),
+                ..list_args()
+            }),
+            &config,
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        let entry_lines = written.lines().filter(|l| l.starts_with("    ")).count();
+        assert_eq!(entry_lines, 3);
+        assert!(written.contains("(truncated — showing first 3 of "));
+    }
+
+    #[test]
+    fn dependency_status_reports_found_and_missing_binaries() {
+        #[cfg(unix)]
+        assert!(dependency_status("sh").starts_with("sh (found: "));
+        assert_eq!(
+            dependency_status("zeroclaw-definitely-missing-binary"),
+            "zeroclaw-definitely-missing-binary (❌ not found)"
+        );
+    }
+
+    #[test]
+    fn env_var_line_flags_missing_required_variables() {
+        let required = EnvVarSpec {
+            name: "EXAMPLE_TOKEN",
+            description: "Bot token",
+            required: true,
+        };
+        let optional = EnvVarSpec {
+            required: false,
+            ..required
+        };
+
+        assert!(env_var_line(&required, false).contains("❌ not set (required)"));
+        assert!(env_var_line(&required, true).contains("✅ set"));
+        assert!(env_var_line(&optional, false).contains("⚪ not set"));
+        assert!(!env_var_line(&optional, false).contains('❌'));
+    }
+
+    #[test]
+    fn report_issues_line_shows_contact_email_when_set() {
+        let mut entry = entry_with_status(|_| IntegrationStatus::Available);
+        assert_eq!(report_issues_line(&entry), None);
+
+        entry.contact_email = "maintainer@example.com";
+        assert_eq!(
+            report_issues_line(&entry).as_deref(),
+            Some("  Report issues: maintainer@example.com")
+        );
+    }
+
+    #[test]
+    fn changelog_line_shows_service_changelog_when_set() {
+        let mut entry = entry_with_status(|_| IntegrationStatus::Available);
+        assert_eq!(changelog_line(&entry), None);
+
+        entry.changelog_url = "https://example.com/api/changelog";
+        assert_eq!(
+            changelog_line(&entry).as_deref(),
+            Some("  Service changelog: https://example.com/api/changelog")
+        );
+
+        let entries = registry::all_integrations();
+        let anthropic = entries.iter().find(|e| e.name == "Anthropic").unwrap();
+        assert_eq!(
+            changelog_line(anthropic).as_deref(),
+            Some("  Service changelog: https://docs.anthropic.com/en/release-notes/api")
+        );
+    }
+
+    #[test]
+    fn coverage_line_formats_percent_or_fallback() {
+        assert_eq!(coverage_line(Some(87.34)), "  Test coverage: 87.3%");
+        assert_eq!(coverage_line(None), "  (coverage data unavailable)");
+    }
+
+    #[test]
+    fn source_modules_exist_in_the_crate() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let entries = registry::all_integrations();
+        let modules: Vec<_> = entries.iter().filter_map(|e| e.source_module).collect();
+        assert!(modules.contains(&"tools::shell"));
+        for module in modules {
+            let relative = module.replace("::", "/");
+            assert!(
+                src.join(format!("{relative}.rs")).is_file()
+                    || src.join(&relative).join("mod.rs").is_file(),
+                "no module at src/{relative}"
+            );
+        }
+        let lm_studio = entries.iter().find(|e| e.name == "LM Studio").unwrap();
+        assert!(lm_studio.source_module.is_none());
+    }
+
+    #[test]
+    fn latency_line_reads_doctor_history() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config = Config {
+            config_path: tmp.path().join("config.toml"),
+            ..Config::default()
+        };
+        assert_eq!(latency_line(&config, "Telegram"), None);
+        assert!(!state::StateStore::path_for_config(&config).exists());
+
+        let store = state::StateStore::open(&state::StateStore::path_for_config(&config)).unwrap();
+        store
+            .record_check("Telegram", Duration::from_millis(342), true)
+            .unwrap();
+        assert_eq!(
+            latency_line(&config, "Telegram").as_deref(),
+            Some("  Latency p95: 342ms")
+        );
+        assert_eq!(latency_line(&config, "Discord"), None);
+    }
+
+    #[test]
+    fn missing_config_keys_reports_unset_required_keys() {
+        let entries = registry::all_integrations();
+        let slack = entries.iter().find(|e| e.name == "Slack").unwrap();
+        let mut config = Config::default();
+        assert_eq!(
+            missing_config_keys(slack, &config),
+            ["channels_config.slack.bot_token"]
+        );
+
+        config.channels_config.slack = Some(crate::config::SlackConfig {
+            bot_token: "xoxb-test".into(),
+            app_token: None,
+            channel_id: None,
+            allowed_users: Vec::new(),
+            group_reply: None,
+        });
+        assert!(missing_config_keys(slack, &config).is_empty());
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert!(!config_key_is_set(&json, "channels_config.slack.app_token"));
+        assert!(!config_key_is_set(&json, "channels_config.nope.bot_token"));
+        assert!(
+            console::strip_ansi_codes(&config_key_line("api_key", false, true))
+                .ends_with("❌ missing (required)")
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_fails_with_missing_keys() {
+        let config = Config::default();
+        let validate = |name: &str| crate::IntegrationCommands::Validate { name: name.into() };
+
+        let err = handle_command(validate("slack"), &config)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<IntegrationError>(),
+            Some(&IntegrationError::MissingConfigKeys {
+                name: "Slack".into(),
+                missing: vec!["channels_config.slack.bot_token".into()],
+            })
+        );
+        assert!(handle_command(validate("Shell"), &config).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_command_fails_without_test_fn_or_on_rejected_key() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+        let mut config = Config::default();
+        config.integrations.overrides.insert(
+            "OpenAI".into(),
+            crate::config::schema::IntegrationOverrideConfig {
+                base_url: Some(server.uri()),
+            },
+        );
+        let test = |name: &str| crate::IntegrationCommands::Test {
+            name: name.into(),
+            api_key: Some("sk-bad".into()),
+            timeout_secs: Some(5),
+        };
+
+        let err = handle_command(test("Shell"), &config).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<IntegrationError>(),
+            Some(&IntegrationError::NoConnectionTest("Shell".into()))
+        );
+        let err = handle_command(test("openai"), &config).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<IntegrationError>(),
+            Some(IntegrationError::ConnectionTestFailed { name, .. }) if name == "OpenAI"
+        ));
+    }
+
+    #[test]
+    fn quick_test_line_shows_example_command() {
+        let entries = registry::all_integrations();
+        let cron = entries.iter().find(|e| e.name == "Cron").unwrap();
+        assert_eq!(
+            quick_test_line(cron).as_deref(),
+            Some("  Quick test: $ zeroclaw cron list")
+        );
+
+        let twitter = entries.iter().find(|e| e.name == "Twitter/X").unwrap();
+        assert!(quick_test_line(twitter).is_none());
+    }
+
+    #[tokio::test]
+    async fn list_with_output_file_writes_plain_text_atomically() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("integrations.txt");
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                category: Some("chat".into()),
+                output_file: Some(path.clone()),
+                ..list_args()
+            }),
+            &config,
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.contains("Chat Providers"));
+        assert!(!written.contains('\x1b'));
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn stats_counts_and_renders_grand_total() {
+        let entries = [
+            entry_with_status(|_| IntegrationStatus::Active),
+            entry_with_status(|_| IntegrationStatus::Degraded("slow".into())),
+            entry_with_status(|_| IntegrationStatus::Available),
+            entry_with_status(|_| IntegrationStatus::ComingSoon),
+        ];
+        let entries: Vec<_> = entries.iter().collect();
+        let counts = stats_counts(&entries, &Config::default());
+        assert_eq!(counts.len(), 1);
+        let (category, row) = counts.iter().next().unwrap();
+        assert_eq!(*row, [2, 1, 1]);
 
-    let matches: Vec<_> = entries
-        .iter()
-        .filter(|e| {
-            e.name.to_lowercase().contains(&query_lower)
-                || e.description.to_lowercase().contains(&query_lower)
-        })
-        .collect();
+        let table = render_stats_table(&counts);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("Coming Soon"));
+        assert!(lines[1].contains(category.label()));
+        assert!(lines[2].trim_start().starts_with("Total"));
+        assert!(lines[2].ends_with("    4"));
 
-    if matches.is_empty() {
-        println!();
-        println!("  No integrations matching '{query}'.");
-        println!();
-        return Ok(());
+        assert_eq!(
+            stats_json(&counts),
+            serde_json::json!([{
+                "category": category.label(),
+                "active": 2,
+                "available": 1,
+                "coming_soon": 1,
+            }])
+        );
     }
 
-    println!();
-    for entry in &matches {
-        let status = (entry.status_fn)(config);
-        println!(
-            "    {} {:<20} {} — {}",
-            status_icon(status),
-            entry.name,
-            console::style(entry.category.label()).dim(),
-            entry.description,
+    #[test]
+    fn stats_cover_every_registry_entry() {
+        let entries = registry::all_integrations();
+        let counts = stats_counts(&entries, &Config::default());
+        let total: usize = counts.values().flatten().sum();
+        assert_eq!(total, entries.len());
+
+        let table = render_stats_table(&counts);
+        let grand_total = table.lines().last().unwrap();
+        assert!(grand_total.ends_with(&format!(" {}", entries.len())));
+        assert_eq!(
+            stats_json(&counts).as_array().map(Vec::len),
+            Some(counts.len())
         );
     }
-    println!();
-    println!("  {} result(s) for '{query}'.", matches.len());
-    println!();
-    Ok(())
-}
 
-fn show_integration_info(config: &Config, name: &str) -> Result<()> {
-    let entries = registry::all_integrations();
-    let name_lower = name.to_lowercase();
-
-    let Some(entry) = entries.iter().find(|e| e.name.to_lowercase() == name_lower) else {
-        anyhow::bail!(
-            "Unknown integration: {name}. Check README for supported integrations or run `zeroclaw onboard --interactive` to configure channels/providers."
+    #[test]
+    fn display_matches_labels() {
+        assert_eq!(IntegrationCategory::SmartHome.to_string(), "Smart Home");
+        assert_eq!(
+            format!("{:<8}|", IntegrationCategory::Chat),
+            "Chat Providers|"
         );
-    };
+        assert_eq!(format!("{:<8}|", IntegrationCategory::Social), "Social  |");
+        assert_eq!(IntegrationStatus::Active.to_string(), "Active");
+        assert_eq!(IntegrationStatus::Available.to_string(), "Available");
+        assert_eq!(IntegrationStatus::ComingSoon.to_string(), "Coming Soon");
+        assert_eq!(
+            format!(
+                "status: {}",
+                IntegrationStatus::Degraded("token expired".into())
+            ),
+            "status: Degraded (token expired)"
+        );
+        let degraded: IntegrationStatus = "degraded".parse().unwrap();
+        assert_eq!(degraded.to_string(), "Degraded");
+    }
 
-    let status = (entry.status_fn)(config);
-    let icon = status_icon(status);
-    let label = match status {
-        IntegrationStatus::Active => "Active",
-        IntegrationStatus::Available => "Available",
-        IntegrationStatus::ComingSoon => "Coming Soon",
-    };
+    #[tokio::test]
+    async fn import_writes_config_unless_dry_run_and_backs_it_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.config_path = dir.path().join("config.toml");
+        config.workspace_dir = dir.path().join("workspace");
+        config.default_provider = Some("openrouter".into());
+        config.api_key = Some("sk-test".into());
+        config.save().await.unwrap();
+        let file = dir.path().join("integrations.json");
+        std::fs::write(
+            &file,
+            r#"[{"name":"OpenRouter","category":"AiModel","status":"Available","config_keys_present":["default_provider"]}]"#,
+        )
+        .unwrap();
+        let import = |dry_run| crate::IntegrationCommands::Import {
+            file: file.clone(),
+            dry_run,
+        };
+        let before = std::fs::read_to_string(&config.config_path).unwrap();
+        let backups = || {
+            std::fs::read_dir(dir.path())
+                .unwrap()
+                .filter(|e| {
+                    e.as_ref()
+                        .unwrap()
+                        .file_name()
+                        .to_string_lossy()
+                        .ends_with(".pre-import.bak")
+                })
+                .count()
+        };
 
-    println!();
-    println!(
-        "  {} {} — {}",
-        icon,
-        console::style(entry.name).white().bold(),
-        entry.description
-    );
-    println!("  Category: {}", entry.category.label());
-    println!("  Status:   {label}");
-    println!();
+        handle_command(import(true), &config).await.unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&config.config_path).unwrap(),
+            before
+        );
+        assert_eq!(backups(), 0);
 
-    // Show setup hints based on integration
-    match entry.name {
-        "Telegram" => {
-            println!("  Setup:");
-            println!("    1. Message @BotFather on Telegram");
-            println!("    2. Create a bot and copy the token");
-            println!("    3. Run: zeroclaw onboard --channels-only");
-            println!("    4. Start: zeroclaw channel start");
-        }
-        "Discord" => {
-            println!("  Setup:");
-            println!("    1. Go to https://discord.com/developers/applications");
-            println!("    2. Create app → Bot → Copy token");
-            println!("    3. Enable MESSAGE CONTENT intent");
-            println!("    4. Run: zeroclaw onboard --channels-only");
-        }
-        "Slack" => {
-            println!("  Setup:");
-            println!("    1. Go to https://api.slack.com/apps");
-            println!("    2. Create app → Bot Token Scopes → Install");
-            println!("    3. Run: zeroclaw onboard --channels-only");
-        }
-        "OpenRouter" => {
-            println!("  Setup:");
-            println!("    1. Get API key at https://openrouter.ai/keys");
-            println!("    2. Run: zeroclaw onboard");
-            println!("    Access 200+ models with one key.");
-        }
-        "Ollama" => {
-            println!("  Setup:");
-            println!("    1. Install: brew install ollama");
-            println!("    2. Pull a model: ollama pull llama3");
-            println!("    3. Set provider to 'ollama' in config.toml");
-        }
-        "iMessage" => {
-            println!("  Setup (macOS only):");
-            println!("    Uses AppleScript bridge to send/receive iMessages.");
-            println!("    Requires Full Disk Access in System Settings → Privacy.");
-        }
-        "GitHub" => {
-            println!("  Setup:");
-            println!("    1. Create a personal access token at https://github.com/settings/tokens");
-            println!("    2. Add to config: [integrations.github] token = \"ghp_...\"");
-        }
-        "Browser" => {
-            println!("  Built-in:");
-            println!("    ZeroClaw can control Chrome/Chromium for web tasks.");
-            println!("    Uses headless browser automation.");
-        }
-        "Cron" => {
-            println!("  Built-in:");
-            println!("    Schedule tasks in ~/.zeroclaw/workspace/cron/");
-            println!("    Run: zeroclaw cron list");
-        }
-        "Webhooks" => {
-            println!("  Built-in:");
-            println!("    HTTP endpoint for external triggers.");
-            println!("    Run: zeroclaw gateway");
-        }
-        _ => {
-            if status == IntegrationStatus::ComingSoon {
-                println!("  This integration is planned. Stay tuned!");
-                println!("  Track progress: https://github.com/theonlyhennygod/zeroclaw");
-            }
-        }
+        handle_command(import(false), &config).await.unwrap();
+        let after: Config =
+            toml::from_str(&std::fs::read_to_string(&config.config_path).unwrap()).unwrap();
+        assert!(after.api_key.is_none());
+        assert_eq!(after.default_provider.as_deref(), Some("openrouter"));
+        assert_eq!(backups(), 1);
     }
 
-    println!();
-    Ok(())
-}
+    #[test]
+    fn list_integrations_returns_typed_summaries() {
+        let config = Config::default();
+        let summaries = list_integrations(
+            &config,
+            &[IntegrationCategory::Social],
+            Some(IntegrationStatus::ComingSoon),
+        )
+        .unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        assert!(!summaries.is_empty());
+        assert!(summaries
+            .iter()
+            .all(|s| s.category == IntegrationCategory::Social
+                && s.status == IntegrationStatus::ComingSoon));
+        assert!(summaries.iter().any(|s| s.name == "Twitter/X"));
+    }
 
     #[test]
-    fn integration_category_all_includes_every_variant_once() {
-        let all = IntegrationCategory::all();
-        assert_eq!(all.len(), 9);
+    fn category_filter_arg_accepts_comma_separated_list() {
+        assert!(category_filter_arg(None).unwrap().is_empty());
+        assert_eq!(
+            category_filter_arg(Some("chat")).unwrap(),
+            [IntegrationCategory::Chat]
+        );
+        assert_eq!(
+            category_filter_arg(Some("tools, chat,ai,chat")).unwrap(),
+            [
+                IntegrationCategory::Chat,
+                IntegrationCategory::AiModel,
+                IntegrationCategory::ToolsAutomation
+            ]
+        );
 
-        let labels: Vec<&str> = all.iter().map(|cat| cat.label()).collect();
-        assert!(labels.contains(&"Chat Providers"));
-        assert!(labels.contains(&"AI Models"));
-        assert!(labels.contains(&"Productivity"));
-        assert!(labels.contains(&"Music & Audio"));
-        assert!(labels.contains(&"Smart Home"));
-        assert!(labels.contains(&"Tools & Automation"));
-        assert!(labels.contains(&"Media & Creative"));
-        assert!(labels.contains(&"Social"));
-        assert!(labels.contains(&"Platforms"));
+        let err = category_filter_arg(Some("chat,bogus")).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<IntegrationError>(),
+            Some(IntegrationError::InvalidCategory { input, .. }) if input == "bogus"
+        ));
     }
 
     #[test]
-    fn handle_command_info_is_case_insensitive_for_known_integrations() {
+    fn search_integrations_applies_query_and_filters() {
         let config = Config::default();
-        let first_name = registry::all_integrations()
-            .first()
-            .expect("registry should define at least one integration")
-            .name
-            .to_lowercase();
+        let all = search_integrations(&config, "shell", &[], None).unwrap();
+        assert!(all.iter().any(|s| s.name == "Shell"));
+        assert!(all.windows(2).all(|w| w[0].status >= w[1].status));
 
+        let none =
+            search_integrations(&config, "shell", &[IntegrationCategory::Social], None).unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_json_writes_parseable_object() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("integrations.json");
+        let config = Config::default();
         let result = handle_command(
-            crate::IntegrationCommands::Info { name: first_name },
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                category: Some("chat".into()),
+                output_file: Some(path.clone()),
+                json: true,
+                ..list_args()
+            }),
             &config,
+        )
+        .await;
+        assert!(result.is_ok());
+
+        let parsed: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let items = parsed["integrations"].as_array().unwrap();
+        assert!(!items.is_empty());
+        assert!(items
+            .iter()
+            .all(|item| item["category"] == "Chat Providers"));
+        assert!(parsed["registry_checksum"]
+            .as_str()
+            .unwrap()
+            .starts_with("sha256:"));
+    }
+
+    #[test]
+    fn parse_maturity_filter_covers_all_aliases() {
+        assert_eq!(parse_maturity_filter("stable"), Some(Maturity::Stable));
+        assert_eq!(parse_maturity_filter("Beta"), Some(Maturity::Beta));
+        assert_eq!(
+            parse_maturity_filter("experimental"),
+            Some(Maturity::Experimental)
         );
+        assert!(parse_maturity_filter("bogus").is_none());
+    }
 
-        assert!(result.is_ok());
+    #[test]
+    fn platform_label_joins_supported_platforms() {
+        assert_eq!(platform_label(&[Platform::MacOs]), "macOS");
+        assert_eq!(
+            platform_label(&[Platform::MacOs, Platform::Linux]),
+            "macOS, Linux"
+        );
     }
 
     #[test]
-    fn handle_command_info_returns_error_for_unknown_integration() {
+    fn pricing_line_explains_freemium() {
+        assert_eq!(
+            pricing_line(PricingModel::Freemium),
+            "  Pricing:  Freemium (free tier available)"
+        );
+        assert_eq!(
+            pricing_line(PricingModel::PaidOnly),
+            "  Pricing:  Paid only"
+        );
+    }
+
+    #[tokio::test]
+    async fn list_with_maturity_filter_succeeds() {
         let config = Config::default();
         let result = handle_command(
-            crate::IntegrationCommands::Info {
-                name: "definitely-not-a-real-integration".into(),
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                filter_maturity: Some("stable".into()),
+                ..list_args()
+            }),
+            &config,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn search_save_rejects_invalid_filters_without_writing_preset() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.config_path = dir.path().join("config.toml");
+        let result = handle_command(
+            crate::IntegrationCommands::Search {
+                query: Some("foo".into()),
+                category: Some("bogus".into()),
+                category_exclude: None,
+                status: None,
+                match_whole_word: false,
+                min_results: None,
+                json: false,
+                compact: false,
+                verbose: false,
+                explain: false,
+                fuzzy: false,
+                fuzzy_threshold: None,
+                save: Some("p".into()),
+                load: None,
             },
             &config,
+        )
+        .await;
+        assert!(result.is_err());
+        let store = presets::PresetStore::for_config(&config);
+        assert!(store.load("p").unwrap().is_none());
+    }
+
+    #[test]
+    fn search_ranks_tag_match_above_description_only_match() {
+        let by_tag = IntegrationEntry::builder()
+            .name("Zeta Relay")
+            .description("Message relay")
+            .tags(&["mesh"])
+            .category(IntegrationCategory::Chat)
+            .license("MIT")
+            .pricing_model(PricingModel::OpenSource)
+            .status_fn(|_| IntegrationStatus::Available)
+            .build();
+        let by_description = IntegrationEntry::builder()
+            .name("Alpha Bridge")
+            .description("Bridges mesh networks")
+            .category(IntegrationCategory::Chat)
+            .license("MIT")
+            .pricing_model(PricingModel::OpenSource)
+            .status_fn(|_| IntegrationStatus::Available)
+            .build();
+        assert!(
+            SearchScore::for_query(&by_tag, "mesh")
+                > SearchScore::for_query(&by_description, "mesh")
+        );
+
+        let entries = [&by_description, &by_tag];
+        let ranked = matching_entries(&entries, "mesh", false).unwrap();
+        assert_eq!(
+            ranked.iter().map(|e| e.name).collect::<Vec<_>>(),
+            ["Zeta Relay", "Alpha Bridge"]
         );
+    }
+
+    #[test]
+    fn search_matches_tags_not_in_name_or_description() {
+        let config = Config::default();
+        let results = search_integrations(&config, "offline", &[], None).unwrap();
+        assert!(results.iter().any(|s| s.name == "Shell"));
+        assert!(registry::all_integrations()
+            .iter()
+            .find(|e| e.name == "Shell")
+            .is_some_and(|e| !e.description.to_lowercase().contains("offline")));
+    }
 
+    #[tokio::test]
+    async fn list_with_invalid_maturity_fails() {
+        let config = Config::default();
+        let result = handle_command(
+            crate::IntegrationCommands::List(crate::IntegrationListArgs {
+                filter_maturity: Some("alpha".into()),
+                ..list_args()
+            }),
+            &config,
+        )
+        .await;
         assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("Unknown integration"));
+        assert!(result.unwrap_err().to_string().contains("Unknown maturity"));
     }
 
     #[test]
-    fn list_all_integrations_succeeds() {
+    fn is_open_source_license_recognises_osi_licenses() {
+        assert!(is_open_source_license("MIT"));
+        assert!(is_open_source_license("apache 2.0"));
+        assert!(is_open_source_license("AGPL v3"));
+        assert!(is_open_source_license("MIT / Apache 2.0"));
+        assert!(!is_open_source_license("Proprietary"));
+        assert!(!is_open_source_license("Unknown"));
+        assert!(!is_open_source_license("MIT / Proprietary"));
+    }
+
+    #[tokio::test]
+    async fn search_finds_matching_integrations() {
         let config = Config::default();
         let result = handle_command(
-            crate::IntegrationCommands::List {
+            crate::IntegrationCommands::Search {
+                query: Some("telegram".into()),
                 category: None,
+                category_exclude: None,
                 status: None,
+                match_whole_word: false,
+                min_results: None,
+                json: false,
+                compact: false,
+                verbose: false,
+                explain: false,
+                fuzzy: false,
+                fuzzy_threshold: None,
+                save: None,
+                load: None,
             },
             &config,
-        );
+        )
+        .await;
         assert!(result.is_ok());
     }
 
-    #[test]
-    fn list_with_category_filter_succeeds() {
+    #[tokio::test]
+    async fn search_no_match_succeeds() {
         let config = Config::default();
         let result = handle_command(
-            crate::IntegrationCommands::List {
-                category: Some("chat".into()),
+            crate::IntegrationCommands::Search {
+                query: Some("zzz-no-match-zzz".into()),
+                category: None,
+                category_exclude: None,
                 status: None,
+                match_whole_word: false,
+                min_results: None,
+                json: false,
+                compact: false,
+                verbose: false,
+                explain: false,
+                fuzzy: false,
+                fuzzy_threshold: None,
+                save: None,
+                load: None,
             },
             &config,
-        );
+        )
+        .await;
         assert!(result.is_ok());
     }
 
-    #[test]
-    fn list_with_status_filter_succeeds() {
+    #[tokio::test]
+    async fn search_with_category_and_status_filters_succeeds() {
         let config = Config::default();
         let result = handle_command(
-            crate::IntegrationCommands::List {
-                category: None,
+            crate::IntegrationCommands::Search {
+                query: Some("a".into()),
+                category: Some("ai".into()),
+                category_exclude: None,
                 status: Some("available".into()),
+                match_whole_word: false,
+                min_results: None,
+                json: false,
+                compact: false,
+                verbose: false,
+                explain: false,
+                fuzzy: false,
+                fuzzy_threshold: None,
+                save: None,
+                load: None,
             },
             &config,
-        );
+        )
+        .await;
         assert!(result.is_ok());
     }
 
     #[test]
-    fn list_with_invalid_category_fails() {
+    fn explain_match_names_the_matching_field() {
+        let entries = registry::all_integrations();
+        let find = |name: &str| entries.iter().find(|e| e.name == name).unwrap();
+
+        assert_eq!(
+            explain_match(find("Telegram"), "tele"),
+            "[matched: name contains \"tele\"]"
+        );
+        let shell = find("Shell");
+        let word = shell.description.split_whitespace().last().unwrap();
+        assert!(!shell.name.to_lowercase().contains(&word.to_lowercase()));
+        assert!(explain_match(shell, word).contains("description"));
+        assert_eq!(
+            explain_match(shell, "OFFLINE"),
+            "[matched: tag \"offline\"]"
+        );
+    }
+
+    #[tokio::test]
+    async fn search_below_min_results_fails() {
         let config = Config::default();
         let result = handle_command(
-            crate::IntegrationCommands::List {
-                category: Some("nonexistent".into()),
+            crate::IntegrationCommands::Search {
+                query: Some("telegram".into()),
+                category: None,
+                category_exclude: None,
                 status: None,
+                match_whole_word: false,
+                min_results: Some(50),
+                json: false,
+                compact: false,
+                verbose: false,
+                explain: false,
+                fuzzy: false,
+                fuzzy_threshold: None,
+                save: None,
+                load: None,
             },
             &config,
-        );
+        )
+        .await;
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Unknown category"));
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("expected at least 50 results, got "));
+
+        assert!(check_min_results(3, Some(3)).is_ok());
+        assert!(check_min_results(0, None).is_ok());
     }
 
-    #[test]
-    fn list_with_invalid_status_fails() {
+    #[tokio::test]
+    async fn search_without_query_or_preset_fails() {
         let config = Config::default();
         let result = handle_command(
-            crate::IntegrationCommands::List {
+            crate::IntegrationCommands::Search {
+                query: None,
                 category: None,
-                status: Some("bogus".into()),
+                category_exclude: None,
+                status: None,
+                match_whole_word: false,
+                min_results: None,
+                json: false,
+                compact: false,
+                verbose: false,
+                explain: false,
+                fuzzy: false,
+                fuzzy_threshold: None,
+                save: None,
+                load: None,
             },
             &config,
-        );
+        )
+        .await;
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Unknown status"));
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Missing search query"));
     }
 
     #[test]
-    fn search_finds_matching_integrations() {
+    fn category_exclude_hides_entries_from_that_category() {
         let config = Config::default();
-        let result = handle_command(
-            crate::IntegrationCommands::Search {
-                query: "telegram".into(),
-            },
-            &config,
-        );
-        assert!(result.is_ok());
+        let entries = registry::all_integrations();
+        let options = SearchOptions {
+            query: "o",
+            ..SearchOptions::default()
+        };
+        let all = search_matches(&entries, &config, &options).unwrap();
+        assert!(all
+            .iter()
+            .any(|(e, _, _)| e.category == IntegrationCategory::Platform));
+
+        let options = SearchOptions {
+            category_exclude: Some("platforms"),
+            ..options
+        };
+        let filtered = search_matches(&entries, &config, &options).unwrap();
+        assert!(!filtered.is_empty());
+        assert!(!filtered
+            .iter()
+            .any(|(e, _, _)| e.category == IntegrationCategory::Platform));
     }
 
     #[test]
-    fn search_no_match_succeeds() {
-        let config = Config::default();
-        let result = handle_command(
-            crate::IntegrationCommands::Search {
-                query: "zzz-no-match-zzz".into(),
-            },
-            &config,
+    fn ranked_entries_falls_back_to_fuzzy_and_ranks_exact_first() {
+        let entries = registry::all_integrations();
+
+        let typo = ranked_entries(&entries, "telgram", false, false, 0.3).unwrap();
+        assert_eq!(typo.first().map(|(e, _)| e.name), Some("Telegram"));
+        assert!(typo.iter().all(|(_, score)| *score < 1.0));
+
+        let exact = ranked_entries(&entries, "slack", false, false, 0.3).unwrap();
+        assert!(exact.iter().all(|(_, score)| *score == 1.0));
+
+        let fuzzy = ranked_entries(&entries, "slack", false, true, 0.1).unwrap();
+        assert!(fuzzy.len() > exact.len());
+        assert!(fuzzy[..exact.len()].iter().all(|(_, score)| *score == 1.0));
+        assert!(fuzzy.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        let summaries = search_integrations(&Config::default(), "telgram", &[], None).unwrap();
+        assert_eq!(summaries[0].name, "Telegram");
+        assert!(summaries[0]
+            .relevance
+            .is_some_and(|r| (0.3..1.0).contains(&r)));
+    }
+
+    #[test]
+    fn fuzzy_threshold_arg_defaults_and_validates_range() {
+        assert_eq!(
+            fuzzy_threshold_arg(None).unwrap(),
+            search::DEFAULT_THRESHOLD
         );
-        assert!(result.is_ok());
+        assert_eq!(fuzzy_threshold_arg(Some("0.5")).unwrap(), 0.5);
+        assert!(fuzzy_threshold_arg(Some("1.5")).is_err());
+        assert!(fuzzy_threshold_arg(Some("high")).is_err());
+    }
+
+    #[test]
+    fn match_whole_word_excludes_substring_hits() {
+        let entries = registry::all_integrations();
+
+        let loose = matching_entries(&entries, "ai", false).unwrap();
+        assert!(loose.iter().any(|e| e.name == "Email"));
+
+        let whole = matching_entries(&entries, "ai", true).unwrap();
+        assert!(!whole.is_empty());
+        assert!(!whole.iter().any(|e| e.name == "Email"));
     }
 
     #[test]
@@ -479,6 +3003,119 @@ mod tests {
         assert!(parse_status_filter("available").is_some());
         assert!(parse_status_filter("coming-soon").is_some());
         assert!(parse_status_filter("soon").is_some());
+        assert!(parse_status_filter("degraded").is_some());
+        assert!(parse_status_filter("unhealthy").is_some());
+        for alias in ["auth-expired", "expired", "Invalid"] {
+            assert_eq!(
+                parse_status_filter(alias),
+                Some(IntegrationStatus::AuthExpired { since: None })
+            );
+        }
         assert!(parse_status_filter("bogus").is_none());
+
+        let status = |input| parse_status_filter(input).unwrap();
+        assert!(status("active") > status("unhealthy"));
+        assert!(status("degraded") > status("expired"));
+        assert!(status("expired") > status("available"));
+        assert!(status("available") > status("soon"));
+        let mut statuses = vec![status("coming-soon"), status("active"), status("available")];
+        statuses.sort();
+        assert_eq!(
+            statuses,
+            [
+                IntegrationStatus::ComingSoon,
+                IntegrationStatus::Available,
+                IntegrationStatus::Active,
+            ]
+        );
+    }
+
+    #[test]
+    fn category_from_str_maps_every_alias() {
+        let cases: &[(&[&str], IntegrationCategory)] = &[
+            (&["chat"], IntegrationCategory::Chat),
+            (
+                &["ai", "model", "models", "ai-model", "ai-models"],
+                IntegrationCategory::AiModel,
+            ),
+            (&["productivity"], IntegrationCategory::Productivity),
+            (
+                &["music", "audio", "music-audio"],
+                IntegrationCategory::MusicAudio,
+            ),
+            (
+                &["smart-home", "smarthome", "home"],
+                IntegrationCategory::SmartHome,
+            ),
+            (
+                &["tools", "automation", "tools-automation"],
+                IntegrationCategory::ToolsAutomation,
+            ),
+            (
+                &["media", "creative", "media-creative"],
+                IntegrationCategory::MediaCreative,
+            ),
+            (&["social"], IntegrationCategory::Social),
+            (&["platform", "platforms"], IntegrationCategory::Platform),
+        ];
+        for (aliases, expected) in cases {
+            for alias in *aliases {
+                assert_eq!(alias.parse::<IntegrationCategory>(), Ok(*expected));
+                assert_eq!(
+                    alias.to_uppercase().parse::<IntegrationCategory>(),
+                    Ok(*expected)
+                );
+            }
+        }
+
+        let err = "bogus".parse::<IntegrationCategory>().unwrap_err();
+        assert!(matches!(
+            &err,
+            IntegrationError::InvalidCategory { input, .. } if input == "bogus"
+        ));
+        assert!(err.to_string().contains("'bogus'"));
+        assert!(err.to_string().contains("smart-home"));
+    }
+
+    #[test]
+    fn status_from_str_maps_every_alias() {
+        let cases: &[(&[&str], IntegrationStatus)] = &[
+            (&["active"], IntegrationStatus::Active),
+            (&["available"], IntegrationStatus::Available),
+            (
+                &["coming-soon", "comingsoon", "soon"],
+                IntegrationStatus::ComingSoon,
+            ),
+            (
+                &["degraded", "unhealthy"],
+                IntegrationStatus::Degraded(String::new()),
+            ),
+        ];
+        for (aliases, expected) in cases {
+            for alias in *aliases {
+                assert_eq!(alias.parse::<IntegrationStatus>().as_ref(), Ok(expected));
+            }
+        }
+
+        let err = "bogus".parse::<IntegrationStatus>().unwrap_err();
+        assert!(matches!(
+            &err,
+            IntegrationError::InvalidStatus { input, .. } if input == "bogus"
+        ));
+        assert!(err.to_string().contains("coming-soon"));
+    }
+
+    #[test]
+    fn degraded_filter_matches_any_reason() {
+        let filter = parse_status_filter("degraded").unwrap();
+        assert!(status_matches(
+            &IntegrationStatus::Degraded("token expired".into()),
+            &filter
+        ));
+        assert!(!status_matches(&IntegrationStatus::Active, &filter));
+        assert!(status_matches(
+            &IntegrationStatus::Active,
+            &IntegrationStatus::Active
+        ));
     }
 }
@@ -0,0 +1,109 @@
+use crate::config::Config;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// A saved `integrations search` query and its filters
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchPreset {
+    pub query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category_exclude: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// Named search presets persisted as TOML tables (`[my_ai_search]`)
+pub struct PresetStore {
+    path: PathBuf,
+}
+
+impl PresetStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Store next to `config.toml` (`~/.zeroclaw/search_presets.toml` by default).
+    pub fn for_config(config: &Config) -> Self {
+        let dir = config
+            .config_path
+            .parent()
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        Self::new(dir.join("search_presets.toml"))
+    }
+
+    fn read_all(&self) -> Result<BTreeMap<String, SearchPreset>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let raw = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        toml::from_str(&raw).with_context(|| format!("Failed to parse {}", self.path.display()))
+    }
+
+    /// Save `preset` under `name`, replacing any existing preset with that name.
+    pub fn save(&self, name: &str, preset: &SearchPreset) -> Result<()> {
+        let mut presets = self.read_all()?;
+        presets.insert(name.to_string(), preset.clone());
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let raw = toml::to_string_pretty(&presets)?;
+        fs::write(&self.path, raw)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    /// Load the preset saved under `name`, if any.
+    pub fn load(&self, name: &str) -> Result<Option<SearchPreset>> {
+        Ok(self.read_all()?.remove(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let store = PresetStore::new(tmp.path().join("search_presets.toml"));
+        let preset = SearchPreset {
+            query: "ai".into(),
+            category: Some("ai".into()),
+            category_exclude: None,
+            status: None,
+        };
+
+        store.save("my_ai_search", &preset).unwrap();
+        store
+            .save(
+                "chat",
+                &SearchPreset {
+                    query: "bot".into(),
+                    category: None,
+                    category_exclude: Some("platform".into()),
+                    status: Some("active".into()),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(store.load("my_ai_search").unwrap(), Some(preset));
+        assert_eq!(store.load("chat").unwrap().unwrap().query, "bot");
+        assert!(store.load("missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn load_without_file_returns_none() {
+        let tmp = TempDir::new().unwrap();
+        let store = PresetStore::new(tmp.path().join("search_presets.toml"));
+        assert!(store.load("anything").unwrap().is_none());
+    }
+}
@@ -0,0 +1,69 @@
+use super::{IntegrationEntry, IntegrationStatus};
+use ::prometheus::{Encoder, GaugeVec, Opts, Registry, TextEncoder};
+
+/// Gauge value for a status: Active=1, Degraded=0.5, AuthExpired=0.25,
+/// Available=0, ComingSoon=-1.
+fn status_value(status: &IntegrationStatus) -> f64 {
+    match status {
+        IntegrationStatus::Active => 1.0,
+        IntegrationStatus::Degraded(_) => 0.5,
+        IntegrationStatus::AuthExpired { .. } => 0.25,
+        IntegrationStatus::Available => 0.0,
+        IntegrationStatus::ComingSoon => -1.0,
+    }
+}
+
+/// Render `entries` as a `zeroclaw_integration_status` gauge in Prometheus
+/// text exposition format, labelled by name and category.
+pub fn render(entries: &[(&IntegrationEntry, IntegrationStatus)]) -> String {
+    let registry = Registry::new();
+    let gauge = GaugeVec::new(
+        Opts::new(
+            "zeroclaw_integration_status",
+            "Status of an integration (1 active, 0.5 degraded, 0 available, -1 coming soon)",
+        ),
+        &["name", "category"],
+    )
+    .expect("valid metric definition");
+    registry
+        .register(Box::new(gauge.clone()))
+        .expect("metric registered once");
+
+    for (entry, status) in entries {
+        let category = format!("{:?}", entry.category);
+        gauge
+            .with_label_values(&[entry.name, category.as_str()])
+            .set(status_value(status));
+    }
+
+    let mut buf = Vec::new();
+    TextEncoder::new()
+        .encode(&registry.gather(), &mut buf)
+        .unwrap_or_default();
+    String::from_utf8(buf).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::registry;
+
+    #[test]
+    fn render_emits_help_header_and_metric_lines() {
+        let entries = registry::all_integrations();
+        let shell = entries.iter().find(|e| e.name == "Shell").unwrap();
+        let twitter = entries.iter().find(|e| e.name == "Twitter/X").unwrap();
+
+        let out = render(&[
+            (shell, IntegrationStatus::Active),
+            (twitter, IntegrationStatus::ComingSoon),
+        ]);
+        assert!(out.starts_with("# HELP zeroclaw_integration_status "));
+        assert!(out.contains("# TYPE zeroclaw_integration_status gauge"));
+        assert!(out
+            .contains(r#"zeroclaw_integration_status{category="ToolsAutomation",name="Shell"} 1"#));
+        assert!(
+            out.contains(r#"zeroclaw_integration_status{category="Social",name="Twitter/X"} -1"#)
+        );
+    }
+}
@@ -1,733 +1,1736 @@
-use super::{IntegrationCategory, IntegrationEntry, IntegrationStatus};
+use super::{
+    AuthType, EnvVarSpec, IntegrationCategory, IntegrationEntry, IntegrationEntryOwned,
+    IntegrationError, IntegrationStatus, Maturity, MessageType, Platform, PricingModel, StatusFn,
+};
 use crate::providers::{
     is_glm_alias, is_minimax_alias, is_moonshot_alias, is_qianfan_alias, is_qwen_alias,
     is_zai_alias,
 };
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock, PoisonError};
 
-/// Returns the full catalog of integrations
-#[allow(clippy::too_many_lines)]
-pub fn all_integrations() -> Vec<IntegrationEntry> {
-    vec![
-        // ── Chat Providers ──────────────────────────────────────
-        IntegrationEntry {
-            name: "Telegram",
-            description: "Bot API — long-polling",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.telegram.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Discord",
-            description: "Servers, channels & DMs",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.discord.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Slack",
-            description: "Workspace apps via Web API",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.slack.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Webhooks",
-            description: "HTTP endpoint for triggers",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.webhook.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "WhatsApp",
-            description: "Meta Cloud API via webhook",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.whatsapp.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Signal",
-            description: "Privacy-focused via signal-cli",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.signal.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "iMessage",
-            description: "macOS AppleScript bridge",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.imessage.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Microsoft Teams",
-            description: "Enterprise chat support",
-            category: IntegrationCategory::Chat,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Matrix",
-            description: "Matrix protocol (Element)",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.matrix.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Nostr",
-            description: "Decentralized DMs (NIP-04)",
-            category: IntegrationCategory::Chat,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "WebChat",
-            description: "Browser-based chat UI",
-            category: IntegrationCategory::Chat,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Nextcloud Talk",
-            description: "Self-hosted Nextcloud chat",
-            category: IntegrationCategory::Chat,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Zalo",
-            description: "Zalo Bot API",
-            category: IntegrationCategory::Chat,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "DingTalk",
-            description: "DingTalk Stream Mode",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.dingtalk.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "QQ Official",
-            description: "Tencent QQ Bot SDK",
-            category: IntegrationCategory::Chat,
-            status_fn: |c| {
-                if c.channels_config.qq.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        // ── AI Models ───────────────────────────────────────────
-        IntegrationEntry {
-            name: "OpenRouter",
-            description: "Claude Sonnet 4.6, GPT-5.2, Gemini 3.1 Pro",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("openrouter") && c.api_key.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Anthropic",
-            description: "Claude Sonnet 4.6, Claude Opus 4.6",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("anthropic") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "OpenAI",
-            description: "GPT-5.2, GPT-5.2-Codex",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("openai") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Google",
-            description: "Gemini 3.1 Pro, Gemini 3 Flash",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_model
-                    .as_deref()
-                    .is_some_and(|m| m.starts_with("google/"))
-                {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "DeepSeek",
-            description: "DeepSeek-Reasoner, DeepSeek-Chat",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_model
-                    .as_deref()
-                    .is_some_and(|m| m.starts_with("deepseek/"))
-                {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "xAI",
-            description: "Grok 4, Grok 3",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_model
-                    .as_deref()
-                    .is_some_and(|m| m.starts_with("x-ai/"))
-                {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Mistral",
-            description: "Mistral Large Latest, Codestral",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_model
-                    .as_deref()
-                    .is_some_and(|m| m.starts_with("mistral"))
-                {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Ollama",
-            description: "Local models (Llama, etc.)",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("ollama") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Perplexity",
-            description: "Sonar Pro, Sonar Reasoning Pro",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("perplexity") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Hugging Face",
-            description: "Open-source models",
-            category: IntegrationCategory::AiModel,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "LM Studio",
-            description: "Local model server",
-            category: IntegrationCategory::AiModel,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Venice",
-            description: "Venice Llama 3.3 70B and frontier blends",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("venice") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Vercel AI",
-            description: "Gateway for GPT-5.2 and multi-provider routing",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("vercel") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Cloudflare AI",
-            description: "Workers AI + Llama 3.3 / gateway routing",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("cloudflare") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Moonshot",
-            description: "Kimi 2.5 and Kimi Coding",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref().is_some_and(is_moonshot_alias) {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Synthetic",
-            description: "Synthetic-1 and synthetic family models",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("synthetic") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "OpenCode Zen",
-            description: "OpenCode Zen and coding-specialized models",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("opencode") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Z.AI",
-            description: "GLM 4.7 and Z.AI hosted variants",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref().is_some_and(is_zai_alias) {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "GLM",
-            description: "GLM 4.7 and GLM 4.5 family",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref().is_some_and(is_glm_alias) {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "MiniMax",
-            description: "MiniMax M1 and latest multimodal variants",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref().is_some_and(is_minimax_alias) {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Qwen",
-            description: "Qwen Max and Qwen reasoning families",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref().is_some_and(is_qwen_alias) {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Amazon Bedrock",
-            description: "Claude Sonnet 4.5 and Bedrock model catalog",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("bedrock") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Qianfan",
-            description: "ERNIE 4.x and Qianfan model catalog",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref().is_some_and(is_qianfan_alias) {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Groq",
-            description: "Llama 3.3 70B Versatile and low-latency models",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("groq") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Together AI",
-            description: "Llama 3.3 70B Turbo and open model hosting",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("together") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Fireworks AI",
-            description: "DeepSeek / Llama high-throughput inference",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("fireworks") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        IntegrationEntry {
-            name: "Cohere",
-            description: "Command R+ (08-2024) and embedding models",
-            category: IntegrationCategory::AiModel,
-            status_fn: |c| {
-                if c.default_provider.as_deref() == Some("cohere") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        // ── Productivity ────────────────────────────────────────
-        IntegrationEntry {
-            name: "GitHub",
-            description: "Code, issues, PRs",
-            category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Notion",
-            description: "Workspace & databases",
-            category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Apple Notes",
-            description: "Native macOS/iOS notes",
-            category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Apple Reminders",
-            description: "Task management",
-            category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Obsidian",
-            description: "Knowledge graph notes",
-            category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Things 3",
-            description: "GTD task manager",
-            category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Bear Notes",
-            description: "Markdown notes",
-            category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Trello",
-            description: "Kanban boards",
-            category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Linear",
-            description: "Issue tracking",
-            category: IntegrationCategory::Productivity,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        // ── Music & Audio ───────────────────────────────────────
-        IntegrationEntry {
-            name: "Spotify",
-            description: "Music playback control",
-            category: IntegrationCategory::MusicAudio,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Sonos",
-            description: "Multi-room audio",
-            category: IntegrationCategory::MusicAudio,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Shazam",
-            description: "Song recognition",
-            category: IntegrationCategory::MusicAudio,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        // ── Smart Home ──────────────────────────────────────────
-        IntegrationEntry {
-            name: "Home Assistant",
-            description: "Home automation hub",
-            category: IntegrationCategory::SmartHome,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Philips Hue",
-            description: "Smart lighting",
-            category: IntegrationCategory::SmartHome,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "8Sleep",
-            description: "Smart mattress",
-            category: IntegrationCategory::SmartHome,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        // ── Tools & Automation ──────────────────────────────────
-        IntegrationEntry {
-            name: "Browser",
-            description: "Chrome/Chromium control",
-            category: IntegrationCategory::ToolsAutomation,
-            status_fn: |_| IntegrationStatus::Available,
-        },
-        IntegrationEntry {
-            name: "Shell",
-            description: "Terminal command execution",
-            category: IntegrationCategory::ToolsAutomation,
-            status_fn: |_| IntegrationStatus::Active,
-        },
-        IntegrationEntry {
-            name: "File System",
-            description: "Read/write files",
-            category: IntegrationCategory::ToolsAutomation,
-            status_fn: |_| IntegrationStatus::Active,
-        },
-        IntegrationEntry {
-            name: "Cron",
-            description: "Scheduled tasks",
-            category: IntegrationCategory::ToolsAutomation,
-            status_fn: |_| IntegrationStatus::Available,
-        },
-        IntegrationEntry {
-            name: "Voice",
-            description: "Voice wake + talk mode",
-            category: IntegrationCategory::ToolsAutomation,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Gmail",
-            description: "Email triggers & send",
-            category: IntegrationCategory::ToolsAutomation,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "1Password",
-            description: "Secure credentials",
-            category: IntegrationCategory::ToolsAutomation,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Weather",
-            description: "Forecasts & conditions",
-            category: IntegrationCategory::ToolsAutomation,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Canvas",
-            description: "Visual workspace + A2UI",
-            category: IntegrationCategory::ToolsAutomation,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        // ── Media & Creative ────────────────────────────────────
-        IntegrationEntry {
-            name: "Image Gen",
-            description: "AI image generation",
-            category: IntegrationCategory::MediaCreative,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "GIF Search",
-            description: "Find the perfect GIF",
-            category: IntegrationCategory::MediaCreative,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Screen Capture",
-            description: "Screenshot & screen control",
-            category: IntegrationCategory::MediaCreative,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Camera",
-            description: "Photo/video capture",
-            category: IntegrationCategory::MediaCreative,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        // ── Social ──────────────────────────────────────────────
-        IntegrationEntry {
-            name: "Twitter/X",
-            description: "Tweet, reply, search",
-            category: IntegrationCategory::Social,
-            status_fn: |_| IntegrationStatus::ComingSoon,
-        },
-        IntegrationEntry {
-            name: "Email",
-            description: "IMAP/SMTP email channel",
-            category: IntegrationCategory::Social,
-            status_fn: |c| {
-                if c.channels_config.email.is_some() {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
-            },
-        },
-        // ── Platforms ───────────────────────────────────────────
-        IntegrationEntry {
-            name: "macOS",
-            description: "Native support + AppleScript",
-            category: IntegrationCategory::Platform,
-            status_fn: |_| {
-                if cfg!(target_os = "macos") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
+/// Path of this file, shown by `integrations list --debug`
+pub const SOURCE_PATH: &str = file!();
+
+/// Every integration, built in or added with [`register`], sorted by
+/// category (`IntegrationCategory`'s `Ord`) then case-insensitively by name.
+pub fn all_integrations() -> Vec<&'static IntegrationEntry> {
+    merge(catalog(), &REGISTERED.entries())
+}
+
+/// [`all_integrations`] as owned entries, e.g. for plugin hosts that keep
+/// their own copy.
+pub fn all_integrations_owned() -> Vec<IntegrationEntryOwned> {
+    all_integrations()
+        .into_iter()
+        .map(IntegrationEntryOwned::from)
+        .collect()
+}
+
+/// Look up an integration by case-insensitive name, built in or registered.
+pub fn find(name: &str) -> Option<&'static IntegrationEntry> {
+    let key = name.to_lowercase();
+    name_index().get(key.as_str()).copied().or_else(|| {
+        REGISTERED
+            .entries()
+            .into_iter()
+            .find(|e| e.name.to_lowercase() == key)
+    })
+}
+
+/// Add an integration at runtime, e.g. from a plugin crate at startup.
+/// Every `zeroclaw integrations` command sees it from then on. Fails if an
+/// integration with the same (case-insensitive) name already exists.
+///
+/// ```rust,ignore
+/// use zeroclaw::integrations::registry;
+/// use zeroclaw::integrations::{
+///     IntegrationCategory, IntegrationEntryOwned, IntegrationStatus, PricingModel,
+/// };
+///
+/// let mut entry = IntegrationEntryOwned::new(
+///     "Acme Pager",
+///     "Page the on-call engineer",
+///     IntegrationCategory::ToolsAutomation,
+///     "MIT",
+///     PricingModel::OpenSource,
+///     |config| {
+///         if config.composio.enabled {
+///             IntegrationStatus::Active
+///         } else {
+///             IntegrationStatus::Available
+///         }
+///     },
+/// );
+/// entry.tags = &["plugin"];
+/// registry::register(entry)?;
+/// ```
+pub fn register(entry: IntegrationEntryOwned) -> Result<(), IntegrationError> {
+    REGISTERED.insert(entry, name_index())
+}
+
+/// Entries added with [`register`]. They are leaked like the catalog, so
+/// every command can keep handling `&'static IntegrationEntry`.
+static REGISTERED: Registered = Registered(Mutex::new(Vec::new()));
+
+struct Registered(Mutex<Vec<&'static IntegrationEntry>>);
+
+impl Registered {
+    fn entries(&self) -> Vec<&'static IntegrationEntry> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Leak and store `entry` unless its name is taken in `builtin` or here.
+    fn insert(
+        &self,
+        entry: IntegrationEntryOwned,
+        builtin: &HashMap<&'static str, &'static IntegrationEntry>,
+    ) -> Result<(), IntegrationError> {
+        let mut entries = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let key = entry.name.to_lowercase();
+        if builtin.contains_key(key.as_str())
+            || entries.iter().any(|e| e.name.to_lowercase() == key)
+        {
+            return Err(IntegrationError::DuplicateIntegration(entry.name));
+        }
+        entries.push(leak(entry));
+        Ok(())
+    }
+}
+
+fn leak(entry: IntegrationEntryOwned) -> &'static IntegrationEntry {
+    Box::leak(Box::new(IntegrationEntry {
+        name: Box::leak(entry.name.into_boxed_str()),
+        description: Box::leak(entry.description.into_boxed_str()),
+        category: entry.category,
+        license: entry.license,
+        maturity: entry.maturity,
+        pricing_model: entry.pricing_model,
+        platform_support: entry.platform_support,
+        max_message_size: entry.max_message_size,
+        supported_message_types: entry.supported_message_types,
+        system_dependencies: entry.system_dependencies,
+        example_command: entry.example_command,
+        api_base_url: entry.api_base_url,
+        self_hosted: entry.self_hosted,
+        authentication_type: entry.authentication_type,
+        data_residency: entry.data_residency,
+        environment_variables: entry.environment_variables,
+        required_config_keys: entry.required_config_keys,
+        optional_config_keys: entry.optional_config_keys,
+        tags: entry.tags,
+        contact_email: entry.contact_email,
+        changelog_url: entry.changelog_url,
+        source_module: entry.source_module,
+        health_check_fn: entry.health_check_fn,
+        test_fn: entry.test_fn,
+        status_fn: StatusFn::Dyn(Box::leak(entry.status_fn)),
+    }))
+}
+
+/// The catalog followed by `registered`, re-sorted only when there are any.
+fn merge(
+    builtin: &'static [IntegrationEntry],
+    registered: &[&'static IntegrationEntry],
+) -> Vec<&'static IntegrationEntry> {
+    let mut entries: Vec<_> = builtin.iter().chain(registered.iter().copied()).collect();
+    if !registered.is_empty() {
+        entries.sort_by_cached_key(|e| (e.category, e.name.to_lowercase()));
+    }
+    entries
+}
+
+/// The sorted catalog, built and leaked once per process.
+fn catalog() -> &'static [IntegrationEntry] {
+    static CATALOG: OnceLock<&'static [IntegrationEntry]> = OnceLock::new();
+    CATALOG.get_or_init(|| {
+        let mut entries = CATALOG_ENTRIES.to_vec();
+        entries.sort_by_cached_key(|e| (e.category, e.name.to_lowercase()));
+        Box::leak(entries.into_boxed_slice())
+    })
+}
+
+/// Catalog entries in source order; see [`all_integrations`] for the sorted view.
+/// Evaluated at compile time, so an entry missing a required builder field
+/// fails the build.
+static CATALOG_ENTRIES: &[IntegrationEntry] = &[
+    // ── Chat Providers ──────────────────────────────────────
+    #[cfg(feature = "telegram")]
+    IntegrationEntry::builder()
+        .name("Telegram")
+        .description("Bot API — long-polling")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::telegram")
+        .supported_message_types(&[
+            MessageType::Text,
+            MessageType::Image,
+            MessageType::Audio,
+            MessageType::Video,
+            MessageType::File,
+            MessageType::ReactionEmoji,
+            MessageType::Sticker,
+        ])
+        .license("Proprietary")
+        .pricing_model(PricingModel::Free)
+        .max_message_size(crate::channels::telegram::TELEGRAM_MAX_MESSAGE_LENGTH)
+        .example_command("zeroclaw channel doctor")
+        .api_base_url("https://api.telegram.org")
+        .changelog_url("https://core.telegram.org/bots/api-changelog")
+        .authentication_type(AuthType::ApiKey)
+        .required_config_keys(&[
+            "channels_config.telegram.bot_token",
+            "channels_config.telegram.allowed_users",
+        ])
+        .optional_config_keys(&[
+            "channels_config.telegram.base_url",
+            "channels_config.telegram.mention_only",
+            "channels_config.telegram.stream_mode",
+        ])
+        .health_check_fn(super::doctor::telegram)
+        .test_fn(super::live_test::telegram)
+        .status_fn(|c| {
+            if c.channels_config.telegram.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    #[cfg(feature = "discord")]
+    IntegrationEntry::builder()
+        .name("Discord")
+        .description("Servers, channels & DMs")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::discord")
+        .supported_message_types(&[
+            MessageType::Text,
+            MessageType::Image,
+            MessageType::Audio,
+            MessageType::Video,
+            MessageType::File,
+            MessageType::ReactionEmoji,
+            MessageType::Sticker,
+        ])
+        .license("Proprietary")
+        .pricing_model(PricingModel::Free)
+        .max_message_size(crate::channels::discord::DISCORD_MAX_MESSAGE_LENGTH)
+        .example_command("zeroclaw channel doctor")
+        .api_base_url("https://discord.com/api/v10")
+        .changelog_url("https://discord.com/developers/docs/change-log")
+        .authentication_type(AuthType::BearerToken)
+        .data_residency("US")
+        .required_config_keys(&["channels_config.discord.bot_token"])
+        .optional_config_keys(&[
+            "channels_config.discord.guild_id",
+            "channels_config.discord.allowed_users",
+            "channels_config.discord.mention_only",
+        ])
+        .status_fn(|c| {
+            if c.channels_config.discord.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    #[cfg(feature = "slack")]
+    IntegrationEntry::builder()
+        .name("Slack")
+        .description("Workspace apps via Web API")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::slack")
+        .supported_message_types(&[
+            MessageType::Text,
+            MessageType::Image,
+            MessageType::File,
+            MessageType::ReactionEmoji,
+        ])
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .example_command("zeroclaw channel doctor")
+        .api_base_url("https://slack.com/api")
+        .changelog_url("https://api.slack.com/changelog")
+        .authentication_type(AuthType::OAuth2)
+        .data_residency("US")
+        .required_config_keys(&["channels_config.slack.bot_token"])
+        .optional_config_keys(&[
+            "channels_config.slack.app_token",
+            "channels_config.slack.channel_id",
+            "channels_config.slack.allowed_users",
+        ])
+        .status_fn(|c| {
+            if c.channels_config.slack.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Webhooks")
+        .description("HTTP endpoint for triggers")
+        .category(IntegrationCategory::Chat)
+        .license("MIT / Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .example_command("zeroclaw channel doctor")
+        .self_hosted(true)
+        .data_residency("Self-hosted")
+        .required_config_keys(&["channels_config.webhook.port"])
+        .optional_config_keys(&["channels_config.webhook.secret"])
+        .tags(&["self-hosted", "open-source"])
+        .status_fn(|c| {
+            if c.channels_config.webhook.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("WhatsApp")
+        .description("Meta Cloud API via webhook")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::whatsapp")
+        .supported_message_types(&[
+            MessageType::Text,
+            MessageType::Image,
+            MessageType::Audio,
+            MessageType::Video,
+            MessageType::File,
+            MessageType::ReactionEmoji,
+            MessageType::Sticker,
+        ])
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .example_command("zeroclaw channel doctor")
+        .authentication_type(AuthType::BearerToken)
+        .data_residency("US")
+        .required_config_keys(&[
+            "channels_config.whatsapp.access_token",
+            "channels_config.whatsapp.phone_number_id",
+            "channels_config.whatsapp.verify_token",
+        ])
+        .optional_config_keys(&[
+            "channels_config.whatsapp.app_secret",
+            "channels_config.whatsapp.allowed_numbers",
+        ])
+        .status_fn(|c| {
+            if c.channels_config.whatsapp.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Signal")
+        .description("Privacy-focused via signal-cli")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::signal")
+        .supported_message_types(&[
+            MessageType::Text,
+            MessageType::Image,
+            MessageType::Audio,
+            MessageType::Video,
+            MessageType::File,
+            MessageType::ReactionEmoji,
+            MessageType::Sticker,
+        ])
+        .license("GPL v3")
+        .maturity(Maturity::Beta)
+        .pricing_model(PricingModel::OpenSource)
+        .example_command("zeroclaw channel doctor")
+        .data_residency("US")
+        .required_config_keys(&[
+            "channels_config.signal.http_url",
+            "channels_config.signal.account",
+        ])
+        .optional_config_keys(&[
+            "channels_config.signal.group_id",
+            "channels_config.signal.allowed_from",
+        ])
+        .tags(&["open-source"])
+        .status_fn(|c| {
+            if c.channels_config.signal.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("iMessage")
+        .description("macOS AppleScript bridge")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::imessage")
+        .supported_message_types(&[
+            MessageType::Text,
+            MessageType::Image,
+            MessageType::Audio,
+            MessageType::Video,
+            MessageType::File,
+            MessageType::ReactionEmoji,
+        ])
+        .license("Proprietary")
+        .maturity(Maturity::Beta)
+        .pricing_model(PricingModel::Free)
+        .platform_support(&[Platform::MacOs])
+        .system_dependencies(&["osascript"])
+        .example_command("zeroclaw channel doctor")
+        .data_residency("US")
+        .required_config_keys(&["channels_config.imessage.allowed_contacts"])
+        .status_fn(|c| {
+            if c.channels_config.imessage.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Microsoft Teams")
+        .description("Enterprise chat support")
+        .category(IntegrationCategory::Chat)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::OAuth2)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Matrix")
+        .description("Matrix protocol (Element)")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::matrix")
+        .supported_message_types(&[
+            MessageType::Text,
+            MessageType::Image,
+            MessageType::Audio,
+            MessageType::Video,
+            MessageType::File,
+            MessageType::ReactionEmoji,
+        ])
+        .license("Apache 2.0")
+        .maturity(Maturity::Beta)
+        .pricing_model(PricingModel::OpenSource)
+        .example_command("zeroclaw channel doctor")
+        .api_base_url("https://matrix.org")
+        .self_hosted(true)
+        .authentication_type(AuthType::BearerToken)
+        .data_residency("Self-hosted")
+        .required_config_keys(&[
+            "channels_config.matrix.homeserver",
+            "channels_config.matrix.access_token",
+            "channels_config.matrix.room_id",
+            "channels_config.matrix.allowed_users",
+        ])
+        .optional_config_keys(&[
+            "channels_config.matrix.user_id",
+            "channels_config.matrix.device_id",
+        ])
+        .tags(&["self-hosted", "open-source"])
+        .status_fn(|c| {
+            if c.channels_config.matrix.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Nostr")
+        .description("Decentralized DMs (NIP-04)")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::nostr")
+        .license("Public Domain")
+        .pricing_model(PricingModel::OpenSource)
+        .tags(&["open-source"])
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("WebChat")
+        .description("Browser-based chat UI")
+        .category(IntegrationCategory::Chat)
+        .license("MIT / Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .self_hosted(true)
+        .data_residency("Self-hosted")
+        .tags(&["self-hosted", "open-source"])
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Nextcloud Talk")
+        .description("Self-hosted Nextcloud chat")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::nextcloud_talk")
+        .license("AGPL v3")
+        .pricing_model(PricingModel::OpenSource)
+        .self_hosted(true)
+        .authentication_type(AuthType::BasicAuth)
+        .data_residency("Self-hosted")
+        .tags(&["self-hosted", "open-source"])
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Zalo")
+        .description("Zalo Bot API")
+        .category(IntegrationCategory::Chat)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Free)
+        .authentication_type(AuthType::OAuth2)
+        .data_residency("VN")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("DingTalk")
+        .description("DingTalk Stream Mode")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::dingtalk")
+        .supported_message_types(&[MessageType::Text, MessageType::Image, MessageType::File])
+        .license("Proprietary")
+        .maturity(Maturity::Beta)
+        .pricing_model(PricingModel::Free)
+        .example_command("zeroclaw channel doctor")
+        .authentication_type(AuthType::OAuth2)
+        .data_residency("CN")
+        .required_config_keys(&[
+            "channels_config.dingtalk.client_id",
+            "channels_config.dingtalk.client_secret",
+        ])
+        .optional_config_keys(&["channels_config.dingtalk.allowed_users"])
+        .status_fn(|c| {
+            if c.channels_config.dingtalk.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("QQ Official")
+        .description("Tencent QQ Bot SDK")
+        .category(IntegrationCategory::Chat)
+        .source_module("channels::qq")
+        .supported_message_types(&[MessageType::Text, MessageType::Image])
+        .license("Proprietary")
+        .maturity(Maturity::Beta)
+        .pricing_model(PricingModel::Free)
+        .example_command("zeroclaw channel doctor")
+        .authentication_type(AuthType::OAuth2)
+        .data_residency("CN")
+        .required_config_keys(&["channels_config.qq.app_id", "channels_config.qq.app_secret"])
+        .optional_config_keys(&[
+            "channels_config.qq.allowed_users",
+            "channels_config.qq.receive_mode",
+        ])
+        .status_fn(|c| {
+            if c.channels_config.qq.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    // ── AI Models ───────────────────────────────────────────
+    IntegrationEntry::builder()
+        .name("OpenRouter")
+        .description("Claude Sonnet 4.6, GPT-5.2, Gemini 3.1 Pro")
+        .category(IntegrationCategory::AiModel)
+        .source_module("providers::openrouter")
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .example_command("zeroclaw agent -p openrouter -m 'Hello'")
+        .api_base_url("https://openrouter.ai/api/v1")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[EnvVarSpec {
+            name: "OPENROUTER_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider", "api_key"])
+        .optional_config_keys(&["default_model"])
+        .tags(&["requires-api-key"])
+        .test_fn(super::live_test::openrouter)
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("openrouter") && c.api_key.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Anthropic")
+        .description("Claude Sonnet 4.6, Claude Opus 4.6")
+        .category(IntegrationCategory::AiModel)
+        .source_module("providers::anthropic")
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .example_command("zeroclaw agent -p anthropic -m 'Hello'")
+        .api_base_url("https://api.anthropic.com")
+        .changelog_url("https://docs.anthropic.com/en/release-notes/api")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[
+            EnvVarSpec {
+                name: "ANTHROPIC_OAUTH_TOKEN",
+                description: "Setup token, checked before ANTHROPIC_API_KEY",
+                required: false,
             },
-        },
-        IntegrationEntry {
-            name: "Linux",
-            description: "Native support",
-            category: IntegrationCategory::Platform,
-            status_fn: |_| {
-                if cfg!(target_os = "linux") {
-                    IntegrationStatus::Active
-                } else {
-                    IntegrationStatus::Available
-                }
+            EnvVarSpec {
+                name: "ANTHROPIC_API_KEY",
+                description: "API key, used when config.toml has no api_key",
+                required: false,
             },
-        },
-        IntegrationEntry {
-            name: "Windows",
-            description: "WSL2 recommended",
-            category: IntegrationCategory::Platform,
-            status_fn: |_| IntegrationStatus::Available,
-        },
-        IntegrationEntry {
-            name: "iOS",
-            description: "Chat via Telegram/Discord",
-            category: IntegrationCategory::Platform,
-            status_fn: |_| IntegrationStatus::Available,
-        },
-        IntegrationEntry {
-            name: "Android",
-            description: "Chat via Telegram/Discord",
-            category: IntegrationCategory::Platform,
-            status_fn: |_| IntegrationStatus::Available,
-        },
-    ]
+        ])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .test_fn(super::live_test::anthropic)
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("anthropic") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("OpenAI")
+        .description("GPT-5.2, GPT-5.2-Codex")
+        .category(IntegrationCategory::AiModel)
+        .source_module("providers::openai")
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .example_command("zeroclaw agent -p openai -m 'Hello'")
+        .api_base_url("https://api.openai.com/v1")
+        .changelog_url("https://platform.openai.com/docs/changelog")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[EnvVarSpec {
+            name: "OPENAI_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .test_fn(super::live_test::openai)
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("openai") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Google")
+        .description("Gemini 3.1 Pro, Gemini 3 Flash")
+        .category(IntegrationCategory::AiModel)
+        .source_module("providers::gemini")
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .required_config_keys(&["default_model"])
+        .optional_config_keys(&["api_key"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_model
+                .as_deref()
+                .is_some_and(|m| m.starts_with("google/"))
+            {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("DeepSeek")
+        .description("DeepSeek-Reasoner, DeepSeek-Chat")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("CN")
+        .required_config_keys(&["default_model"])
+        .optional_config_keys(&["api_key"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_model
+                .as_deref()
+                .is_some_and(|m| m.starts_with("deepseek/"))
+            {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("xAI")
+        .description("Grok 4, Grok 3")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .api_base_url("https://api.x.ai")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .required_config_keys(&["default_model"])
+        .optional_config_keys(&["api_key"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_model
+                .as_deref()
+                .is_some_and(|m| m.starts_with("x-ai/"))
+            {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Mistral")
+        .description("Mistral Large Latest, Codestral")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("EU")
+        .required_config_keys(&["default_model"])
+        .optional_config_keys(&["api_key"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_model
+                .as_deref()
+                .is_some_and(|m| m.starts_with("mistral"))
+            {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    #[cfg(feature = "ollama")]
+    IntegrationEntry::builder()
+        .name("Ollama")
+        .description("Local models (Llama, etc.)")
+        .category(IntegrationCategory::AiModel)
+        .source_module("providers::ollama")
+        .license("MIT")
+        .pricing_model(PricingModel::OpenSource)
+        .system_dependencies(&["ollama"])
+        .example_command("zeroclaw agent -p ollama -m 'Hello'")
+        .api_base_url("http://localhost:11434")
+        .self_hosted(true)
+        .data_residency("Self-hosted")
+        .environment_variables(&[EnvVarSpec {
+            name: "OLLAMA_API_KEY",
+            description: "API key for remote Ollama endpoints",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_url", "default_model"])
+        .tags(&["self-hosted", "offline", "open-source"])
+        .health_check_fn(super::doctor::ollama)
+        .test_fn(super::live_test::ollama)
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("ollama") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Perplexity")
+        .description("Sonar Pro, Sonar Reasoning Pro")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .example_command("zeroclaw agent -p perplexity -m 'Hello'")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[EnvVarSpec {
+            name: "PERPLEXITY_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("perplexity") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Hugging Face")
+        .description("Open-source models")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::BearerToken)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("LM Studio")
+        .description("Local model server")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Free)
+        .api_base_url("http://localhost:1234/v1")
+        .self_hosted(true)
+        .data_residency("Self-hosted")
+        .tags(&["self-hosted", "offline"])
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Venice")
+        .description("Venice Llama 3.3 70B and frontier blends")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .example_command("zeroclaw agent -p venice -m 'Hello'")
+        .api_base_url("https://api.venice.ai")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[EnvVarSpec {
+            name: "VENICE_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("venice") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Vercel AI")
+        .description("Gateway for GPT-5.2 and multi-provider routing")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .example_command("zeroclaw agent -p vercel -m 'Hello'")
+        .api_base_url("https://ai-gateway.vercel.sh/v1")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[EnvVarSpec {
+            name: "VERCEL_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("vercel") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Cloudflare AI")
+        .description("Workers AI + Llama 3.3 / gateway routing")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .example_command("zeroclaw agent -p cloudflare -m 'Hello'")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[EnvVarSpec {
+            name: "CLOUDFLARE_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("cloudflare") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Moonshot")
+        .description("Kimi 2.5 and Kimi Coding")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .api_base_url("https://api.moonshot.cn")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("CN")
+        .environment_variables(&[EnvVarSpec {
+            name: "MOONSHOT_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref().is_some_and(is_moonshot_alias) {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Synthetic")
+        .description("Synthetic-1 and synthetic family models")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .example_command("zeroclaw agent -p synthetic -m 'Hello'")
+        .authentication_type(AuthType::ApiKey)
+        .environment_variables(&[EnvVarSpec {
+            name: "SYNTHETIC_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("synthetic") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("OpenCode Zen")
+        .description("OpenCode Zen and coding-specialized models")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .example_command("zeroclaw agent -p opencode -m 'Hello'")
+        .api_base_url("https://opencode.ai/zen/v1")
+        .authentication_type(AuthType::ApiKey)
+        .environment_variables(&[EnvVarSpec {
+            name: "OPENCODE_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("opencode") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Z.AI")
+        .description("GLM 4.7 and Z.AI hosted variants")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .api_base_url("https://api.z.ai/api/paas/v4")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("CN")
+        .environment_variables(&[EnvVarSpec {
+            name: "ZAI_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref().is_some_and(is_zai_alias) {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("GLM")
+        .description("GLM 4.7 and GLM 4.5 family")
+        .category(IntegrationCategory::AiModel)
+        .source_module("providers::glm")
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .api_base_url("https://open.bigmodel.cn/api/paas/v4")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("CN")
+        .environment_variables(&[EnvVarSpec {
+            name: "GLM_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref().is_some_and(is_glm_alias) {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("MiniMax")
+        .description("MiniMax M1 and latest multimodal variants")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .api_base_url("https://api.minimaxi.com/v1")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("CN")
+        .environment_variables(&[EnvVarSpec {
+            name: "MINIMAX_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref().is_some_and(is_minimax_alias) {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Qwen")
+        .description("Qwen Max and Qwen reasoning families")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .api_base_url("https://dashscope.aliyuncs.com/compatible-mode/v1")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("CN")
+        .environment_variables(&[EnvVarSpec {
+            name: "DASHSCOPE_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref().is_some_and(is_qwen_alias) {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Amazon Bedrock")
+        .description("Claude Sonnet 4.5 and Bedrock model catalog")
+        .category(IntegrationCategory::AiModel)
+        .source_module("providers::bedrock")
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .example_command("zeroclaw agent -p bedrock -m 'Hello'")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("bedrock") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Qianfan")
+        .description("ERNIE 4.x and Qianfan model catalog")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("CN")
+        .environment_variables(&[EnvVarSpec {
+            name: "QIANFAN_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref().is_some_and(is_qianfan_alias) {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Groq")
+        .description("Llama 3.3 70B Versatile and low-latency models")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .example_command("zeroclaw agent -p groq -m 'Hello'")
+        .api_base_url("https://api.groq.com/openai/v1")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[EnvVarSpec {
+            name: "GROQ_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("groq") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Together AI")
+        .description("Llama 3.3 70B Turbo and open model hosting")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .example_command("zeroclaw agent -p together -m 'Hello'")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[EnvVarSpec {
+            name: "TOGETHER_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("together") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Fireworks AI")
+        .description("DeepSeek / Llama high-throughput inference")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .example_command("zeroclaw agent -p fireworks -m 'Hello'")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .environment_variables(&[EnvVarSpec {
+            name: "FIREWORKS_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("fireworks") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Cohere")
+        .description("Command R+ (08-2024) and embedding models")
+        .category(IntegrationCategory::AiModel)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .example_command("zeroclaw agent -p cohere -m 'Hello'")
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("CA")
+        .environment_variables(&[EnvVarSpec {
+            name: "COHERE_API_KEY",
+            description: "API key, used when config.toml has no api_key",
+            required: false,
+        }])
+        .required_config_keys(&["default_provider"])
+        .optional_config_keys(&["api_key", "default_model"])
+        .tags(&["requires-api-key"])
+        .status_fn(|c| {
+            if c.default_provider.as_deref() == Some("cohere") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    // ── Productivity ────────────────────────────────────────
+    IntegrationEntry::builder()
+        .name("GitHub")
+        .description("Code, issues, PRs")
+        .category(IntegrationCategory::Productivity)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::OAuth2)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Notion")
+        .description("Workspace & databases")
+        .category(IntegrationCategory::Productivity)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::BearerToken)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Apple Notes")
+        .description("Native macOS/iOS notes")
+        .category(IntegrationCategory::Productivity)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Free)
+        .platform_support(&[Platform::MacOs])
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Apple Reminders")
+        .description("Task management")
+        .category(IntegrationCategory::Productivity)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Free)
+        .platform_support(&[Platform::MacOs])
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Obsidian")
+        .description("Knowledge graph notes")
+        .category(IntegrationCategory::Productivity)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Free)
+        .data_residency("Local")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Things 3")
+        .description("GTD task manager")
+        .category(IntegrationCategory::Productivity)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .platform_support(&[Platform::MacOs])
+        .data_residency("Local")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Bear Notes")
+        .description("Markdown notes")
+        .category(IntegrationCategory::Productivity)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .platform_support(&[Platform::MacOs])
+        .data_residency("Local")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Trello")
+        .description("Kanban boards")
+        .category(IntegrationCategory::Productivity)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Linear")
+        .description("Issue tracking")
+        .category(IntegrationCategory::Productivity)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    // ── Music & Audio ───────────────────────────────────────
+    IntegrationEntry::builder()
+        .name("Spotify")
+        .description("Music playback control")
+        .category(IntegrationCategory::MusicAudio)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::OAuth2)
+        .data_residency("EU")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Sonos")
+        .description("Multi-room audio")
+        .category(IntegrationCategory::MusicAudio)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Shazam")
+        .description("Song recognition")
+        .category(IntegrationCategory::MusicAudio)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    // ── Smart Home ──────────────────────────────────────────
+    IntegrationEntry::builder()
+        .name("Home Assistant")
+        .description("Home automation hub")
+        .category(IntegrationCategory::SmartHome)
+        .license("Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .self_hosted(true)
+        .authentication_type(AuthType::BearerToken)
+        .data_residency("Self-hosted")
+        .tags(&["self-hosted", "open-source"])
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Philips Hue")
+        .description("Smart lighting")
+        .category(IntegrationCategory::SmartHome)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .authentication_type(AuthType::ApiKey)
+        .data_residency("EU")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("8Sleep")
+        .description("Smart mattress")
+        .category(IntegrationCategory::SmartHome)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .authentication_type(AuthType::BasicAuth)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    // ── Tools & Automation ──────────────────────────────────
+    IntegrationEntry::builder()
+        .name("Browser")
+        .description("Chrome/Chromium control")
+        .category(IntegrationCategory::ToolsAutomation)
+        .source_module("tools::browser")
+        .license("BSD 3-Clause")
+        .maturity(Maturity::Experimental)
+        .pricing_model(PricingModel::OpenSource)
+        .self_hosted(true)
+        .data_residency("Self-hosted")
+        .status_fn(|_| IntegrationStatus::Available)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Shell")
+        .description("Terminal command execution")
+        .category(IntegrationCategory::ToolsAutomation)
+        .source_module("tools::shell")
+        .license("MIT / Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .self_hosted(true)
+        .data_residency("Self-hosted")
+        .tags(&["offline", "open-source"])
+        .status_fn(|_| IntegrationStatus::Active)
+        .build(),
+    IntegrationEntry::builder()
+        .name("File System")
+        .description("Read/write files")
+        .category(IntegrationCategory::ToolsAutomation)
+        .license("MIT / Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .self_hosted(true)
+        .data_residency("Self-hosted")
+        .tags(&["offline", "open-source"])
+        .status_fn(|_| IntegrationStatus::Active)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Cron")
+        .description("Scheduled tasks")
+        .category(IntegrationCategory::ToolsAutomation)
+        .source_module("cron")
+        .license("MIT / Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .example_command("zeroclaw cron list")
+        .self_hosted(true)
+        .data_residency("Self-hosted")
+        .tags(&["offline", "open-source"])
+        .status_fn(|_| IntegrationStatus::Available)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Voice")
+        .description("Voice wake + talk mode")
+        .category(IntegrationCategory::ToolsAutomation)
+        .license("MIT / Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Gmail")
+        .description("Email triggers & send")
+        .category(IntegrationCategory::ToolsAutomation)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::OAuth2)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("1Password")
+        .description("Secure credentials")
+        .category(IntegrationCategory::ToolsAutomation)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .authentication_type(AuthType::BearerToken)
+        .data_residency("CA")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Weather")
+        .description("Forecasts & conditions")
+        .category(IntegrationCategory::ToolsAutomation)
+        .license("Unknown")
+        .pricing_model(PricingModel::Free)
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Canvas")
+        .description("Visual workspace + A2UI")
+        .category(IntegrationCategory::ToolsAutomation)
+        .license("MIT / Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .data_residency("Local")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    // ── Media & Creative ────────────────────────────────────
+    IntegrationEntry::builder()
+        .name("Image Gen")
+        .description("AI image generation")
+        .category(IntegrationCategory::MediaCreative)
+        .license("Unknown")
+        .pricing_model(PricingModel::Freemium)
+        .authentication_type(AuthType::ApiKey)
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("GIF Search")
+        .description("Find the perfect GIF")
+        .category(IntegrationCategory::MediaCreative)
+        .license("Unknown")
+        .pricing_model(PricingModel::Free)
+        .authentication_type(AuthType::ApiKey)
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Screen Capture")
+        .description("Screenshot & screen control")
+        .category(IntegrationCategory::MediaCreative)
+        .source_module("tools::screenshot")
+        .license("MIT / Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .data_residency("Local")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Camera")
+        .description("Photo/video capture")
+        .category(IntegrationCategory::MediaCreative)
+        .license("MIT / Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .data_residency("Local")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    // ── Social ──────────────────────────────────────────────
+    IntegrationEntry::builder()
+        .name("Twitter/X")
+        .description("Tweet, reply, search")
+        .category(IntegrationCategory::Social)
+        .license("Proprietary")
+        .pricing_model(PricingModel::PaidOnly)
+        .authentication_type(AuthType::OAuth2)
+        .data_residency("US")
+        .status_fn(|_| IntegrationStatus::ComingSoon)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Email")
+        .description("IMAP/SMTP email channel")
+        .category(IntegrationCategory::Social)
+        .source_module("channels::email_channel")
+        .supported_message_types(&[MessageType::Text, MessageType::File])
+        .license("MIT / Apache 2.0")
+        .maturity(Maturity::Beta)
+        .pricing_model(PricingModel::OpenSource)
+        .example_command("zeroclaw channel doctor")
+        .authentication_type(AuthType::BasicAuth)
+        .required_config_keys(&[
+            "channels_config.email.imap_host",
+            "channels_config.email.smtp_host",
+            "channels_config.email.username",
+            "channels_config.email.password",
+            "channels_config.email.from_address",
+        ])
+        .optional_config_keys(&[
+            "channels_config.email.imap_port",
+            "channels_config.email.smtp_port",
+            "channels_config.email.allowed_senders",
+        ])
+        .status_fn(|c| {
+            if c.channels_config.email.is_some() {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    // ── Platforms ───────────────────────────────────────────
+    IntegrationEntry::builder()
+        .name("macOS")
+        .description("Native support + AppleScript")
+        .category(IntegrationCategory::Platform)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Free)
+        .platform_support(&[Platform::MacOs])
+        .data_residency("Local")
+        .status_fn(|_| {
+            if cfg!(target_os = "macos") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Linux")
+        .description("Native support")
+        .category(IntegrationCategory::Platform)
+        .license("GPL v2")
+        .pricing_model(PricingModel::OpenSource)
+        .platform_support(&[Platform::Linux])
+        .data_residency("Local")
+        .tags(&["open-source"])
+        .status_fn(|_| {
+            if cfg!(target_os = "linux") {
+                IntegrationStatus::Active
+            } else {
+                IntegrationStatus::Available
+            }
+        })
+        .build(),
+    IntegrationEntry::builder()
+        .name("Windows")
+        .description("WSL2 recommended")
+        .category(IntegrationCategory::Platform)
+        .license("Proprietary")
+        .maturity(Maturity::Beta)
+        .pricing_model(PricingModel::PaidOnly)
+        .platform_support(&[Platform::Windows])
+        .data_residency("Local")
+        .status_fn(|_| IntegrationStatus::Available)
+        .build(),
+    IntegrationEntry::builder()
+        .name("iOS")
+        .description("Chat via Telegram/Discord")
+        .category(IntegrationCategory::Platform)
+        .license("Proprietary")
+        .pricing_model(PricingModel::Free)
+        .data_residency("Local")
+        .status_fn(|_| IntegrationStatus::Available)
+        .build(),
+    IntegrationEntry::builder()
+        .name("Android")
+        .description("Chat via Telegram/Discord")
+        .category(IntegrationCategory::Platform)
+        .license("Apache 2.0")
+        .pricing_model(PricingModel::OpenSource)
+        .data_residency("Local")
+        .tags(&["open-source"])
+        .status_fn(|_| IntegrationStatus::Available)
+        .build(),
+];
+
+/// Built-in registry entries keyed by lowercased name, built once per
+/// process. Use [`find`] to also see entries added with [`register`].
+pub fn name_index() -> &'static HashMap<&'static str, &'static IntegrationEntry> {
+    static INDEX: OnceLock<HashMap<&'static str, &'static IntegrationEntry>> = OnceLock::new();
+    INDEX.get_or_init(|| build_name_index(catalog()))
+}
+
+/// Index `entries` by lowercased name. The lowercased keys are leaked, so
+/// build an index once and keep it rather than rebuilding per lookup.
+pub fn build_name_index(
+    entries: &'static [IntegrationEntry],
+) -> HashMap<&'static str, &'static IntegrationEntry> {
+    entries
+        .iter()
+        .map(|e| {
+            let key: &'static str = Box::leak(e.name.to_lowercase().into_boxed_str());
+            (key, e)
+        })
+        .collect()
+}
+
+/// Returns a `sha256:<hex>` digest of the built-in registry catalog.
+///
+/// Every entry is serialized in full as an [`IntegrationEntryOwned`] (all
+/// static fields; callbacks are skipped, so no status is resolved) and the
+/// JSON lines are sorted before hashing. The value changes whenever a
+/// built-in entry is added, removed, or edited — not when the catalog is
+/// reordered. Computed once per process; entries added with [`register`]
+/// are not covered.
+pub fn checksum() -> &'static str {
+    static CHECKSUM: OnceLock<String> = OnceLock::new();
+    CHECKSUM.get_or_init(|| checksum_of(&catalog().iter().collect::<Vec<_>>()))
+}
+
+fn checksum_of(entries: &[&'static IntegrationEntry]) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut lines: Vec<String> = entries
+        .iter()
+        .map(|&e| serde_json::to_string(&IntegrationEntryOwned::from(e)).unwrap_or_default())
+        .collect();
+    lines.sort();
+
+    let digest = Sha256::digest(lines.join("\n").as_bytes());
+    format!("sha256:{}", hex::encode(digest))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::schema::{IMessageConfig, MatrixConfig, StreamMode, TelegramConfig};
+    use crate::config::schema::{IMessageConfig, MatrixConfig};
+    #[cfg(feature = "telegram")]
+    use crate::config::schema::{StreamMode, TelegramConfig};
     use crate::config::Config;
 
+    #[test]
+    #[cfg(feature = "telegram")]
+    fn telegram_entry_present_with_feature() {
+        let entries = all_integrations();
+        assert!(entries.iter().any(|e| e.name == "Telegram"));
+    }
+
+    #[test]
+    fn max_message_size_matches_channel_limits() {
+        let entries = all_integrations();
+        let limit = |name: &str| {
+            entries
+                .iter()
+                .find(|e| e.name == name)
+                .and_then(|e| e.max_message_size)
+        };
+        #[cfg(feature = "telegram")]
+        assert_eq!(limit("Telegram"), Some(4096));
+        #[cfg(feature = "discord")]
+        assert_eq!(limit("Discord"), Some(2000));
+        assert_eq!(limit("Shell"), None);
+    }
+
+    #[test]
+    fn all_integrations_is_strictly_sorted_by_category_then_name() {
+        let entries = all_integrations();
+        assert!(entries.windows(2).all(|pair| {
+            (pair[0].category, pair[0].name.to_lowercase())
+                < (pair[1].category, pair[1].name.to_lowercase())
+        }));
+    }
+
+    #[test]
+    fn name_index_covers_every_entry_case_insensitively() {
+        let index = name_index();
+        assert_eq!(index.len(), all_integrations().len());
+        assert_eq!(index.get("shell").map(|e| e.name), Some("Shell"));
+        assert_eq!(index.get("twitter/x").map(|e| e.name), Some("Twitter/X"));
+        assert!(!index.contains_key("Shell"));
+    }
+
+    #[test]
+    fn registered_entries_merge_sorted_and_reject_duplicate_names() {
+        let registered = Registered(Mutex::new(Vec::new()));
+        let owned = |name: &str| {
+            IntegrationEntryOwned::new(
+                name,
+                "Registered at runtime",
+                IntegrationCategory::Chat,
+                "MIT",
+                PricingModel::OpenSource,
+                |config: &Config| {
+                    if config.default_temperature > 1.0 {
+                        IntegrationStatus::Active
+                    } else {
+                        IntegrationStatus::Available
+                    }
+                },
+            )
+        };
+
+        registered
+            .insert(owned("AAA Plugin Chat"), name_index())
+            .unwrap();
+        assert_eq!(
+            registered.insert(owned("shell"), name_index()),
+            Err(IntegrationError::DuplicateIntegration("shell".into()))
+        );
+        assert!(registered
+            .insert(owned("aaa plugin chat"), name_index())
+            .is_err());
+
+        let merged = merge(catalog(), &registered.entries());
+        assert_eq!(merged.len(), catalog().len() + 1);
+        assert_eq!(merged[0].name, "AAA Plugin Chat");
+        assert!(merged.windows(2).all(|pair| {
+            (pair[0].category, pair[0].name.to_lowercase())
+                < (pair[1].category, pair[1].name.to_lowercase())
+        }));
+
+        let mut config = Config::default();
+        assert_eq!(merged[0].status(&config), IntegrationStatus::Available);
+        config.default_temperature = 1.5;
+        assert_eq!(merged[0].status(&config), IntegrationStatus::Active);
+
+        let round_trip = IntegrationEntryOwned::from(merged[0]);
+        assert_eq!(round_trip.name, "AAA Plugin Chat");
+        assert_eq!((round_trip.status_fn)(&config), IntegrationStatus::Active);
+    }
+
     #[test]
     fn registry_has_entries() {
         let entries = all_integrations();
@@ -752,7 +1755,7 @@ mod tests {
         let config = Config::default();
         let entries = all_integrations();
         for entry in &entries {
-            let _ = (entry.status_fn)(&config);
+            let _ = entry.status(&config);
         }
     }
 
@@ -783,6 +1786,48 @@ mod tests {
     }
 
     #[test]
+    fn checksum_is_stable_across_calls() {
+        let first = checksum();
+        let second = checksum();
+        assert_eq!(first, second);
+        assert!(first.starts_with("sha256:"));
+        assert_eq!(first.len(), "sha256:".len() + 64);
+    }
+
+    #[test]
+    fn checksum_covers_every_serialized_field() {
+        let base = || {
+            IntegrationEntry::builder()
+                .name("Checksummed")
+                .description("Test entry")
+                .category(IntegrationCategory::ToolsAutomation)
+                .license("MIT")
+                .pricing_model(PricingModel::OpenSource)
+                .status_fn(|_| IntegrationStatus::Available)
+        };
+        let checksum_one = |builder: crate::integrations::builder::IntegrationEntryBuilder| {
+            checksum_of(&[Box::leak(Box::new(builder.build()))])
+        };
+        let original = checksum_one(base());
+        assert_eq!(original, checksum_one(base()));
+
+        for changed in [
+            base().tags(&["offline"]),
+            base().license("Apache 2.0"),
+            base().maturity(Maturity::Beta),
+            base().optional_config_keys(&["api_key"]),
+            base().supported_message_types(&[MessageType::Image]),
+        ] {
+            assert_ne!(original, checksum_one(changed));
+        }
+        assert_eq!(
+            original,
+            checksum_one(base().status_fn(|_| IntegrationStatus::ComingSoon))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "telegram")]
     fn telegram_active_when_configured() {
         let mut config = Config::default();
         config.channels_config.telegram = Some(TelegramConfig {
@@ -797,18 +1842,16 @@ mod tests {
         });
         let entries = all_integrations();
         let tg = entries.iter().find(|e| e.name == "Telegram").unwrap();
-        assert!(matches!((tg.status_fn)(&config), IntegrationStatus::Active));
+        assert!(matches!(tg.status(&config), IntegrationStatus::Active));
     }
 
     #[test]
+    #[cfg(feature = "telegram")]
     fn telegram_available_when_not_configured() {
         let config = Config::default();
         let entries = all_integrations();
         let tg = entries.iter().find(|e| e.name == "Telegram").unwrap();
-        assert!(matches!(
-            (tg.status_fn)(&config),
-            IntegrationStatus::Available
-        ));
+        assert!(matches!(tg.status(&config), IntegrationStatus::Available));
     }
 
     #[test]
@@ -819,7 +1862,7 @@ mod tests {
         });
         let entries = all_integrations();
         let im = entries.iter().find(|e| e.name == "iMessage").unwrap();
-        assert!(matches!((im.status_fn)(&config), IntegrationStatus::Active));
+        assert!(matches!(im.status(&config), IntegrationStatus::Active));
     }
 
     #[test]
@@ -827,10 +1870,7 @@ mod tests {
         let config = Config::default();
         let entries = all_integrations();
         let im = entries.iter().find(|e| e.name == "iMessage").unwrap();
-        assert!(matches!(
-            (im.status_fn)(&config),
-            IntegrationStatus::Available
-        ));
+        assert!(matches!(im.status(&config), IntegrationStatus::Available));
     }
 
     #[test]
@@ -847,7 +1887,7 @@ mod tests {
         });
         let entries = all_integrations();
         let mx = entries.iter().find(|e| e.name == "Matrix").unwrap();
-        assert!(matches!((mx.status_fn)(&config), IntegrationStatus::Active));
+        assert!(matches!(mx.status(&config), IntegrationStatus::Active));
     }
 
     #[test]
@@ -855,10 +1895,7 @@ mod tests {
         let config = Config::default();
         let entries = all_integrations();
         let mx = entries.iter().find(|e| e.name == "Matrix").unwrap();
-        assert!(matches!(
-            (mx.status_fn)(&config),
-            IntegrationStatus::Available
-        ));
+        assert!(matches!(mx.status(&config), IntegrationStatus::Available));
     }
 
     #[test]
@@ -868,7 +1905,7 @@ mod tests {
         for name in ["Nostr", "Spotify", "Home Assistant"] {
             let entry = entries.iter().find(|e| e.name == name).unwrap();
             assert!(
-                matches!((entry.status_fn)(&config), IntegrationStatus::ComingSoon),
+                matches!(entry.status(&config), IntegrationStatus::ComingSoon),
                 "{name} should be ComingSoon"
             );
         }
@@ -879,10 +1916,7 @@ mod tests {
         let config = Config::default();
         let entries = all_integrations();
         let wa = entries.iter().find(|e| e.name == "WhatsApp").unwrap();
-        assert!(matches!(
-            (wa.status_fn)(&config),
-            IntegrationStatus::Available
-        ));
+        assert!(matches!(wa.status(&config), IntegrationStatus::Available));
     }
 
     #[test]
@@ -891,7 +1925,7 @@ mod tests {
         let entries = all_integrations();
         let email = entries.iter().find(|e| e.name == "Email").unwrap();
         assert!(matches!(
-            (email.status_fn)(&config),
+            email.status(&config),
             IntegrationStatus::Available
         ));
     }
@@ -903,7 +1937,7 @@ mod tests {
         for name in ["Shell", "File System"] {
             let entry = entries.iter().find(|e| e.name == name).unwrap();
             assert!(
-                matches!((entry.status_fn)(&config), IntegrationStatus::Active),
+                matches!(entry.status(&config), IntegrationStatus::Active),
                 "{name} should always be Active"
             );
         }
@@ -914,7 +1948,7 @@ mod tests {
         let config = Config::default();
         let entries = all_integrations();
         let macos = entries.iter().find(|e| e.name == "macOS").unwrap();
-        let status = (macos.status_fn)(&config);
+        let status = macos.status(&config);
         if cfg!(target_os = "macos") {
             assert!(matches!(status, IntegrationStatus::Active));
         } else {
@@ -952,44 +1986,29 @@ mod tests {
         };
 
         let minimax = entries.iter().find(|e| e.name == "MiniMax").unwrap();
-        assert!(matches!(
-            (minimax.status_fn)(&config),
-            IntegrationStatus::Active
-        ));
+        assert!(matches!(minimax.status(&config), IntegrationStatus::Active));
 
         config.default_provider = Some("glm-cn".to_string());
         let glm = entries.iter().find(|e| e.name == "GLM").unwrap();
-        assert!(matches!(
-            (glm.status_fn)(&config),
-            IntegrationStatus::Active
-        ));
+        assert!(matches!(glm.status(&config), IntegrationStatus::Active));
 
         config.default_provider = Some("moonshot-intl".to_string());
         let moonshot = entries.iter().find(|e| e.name == "Moonshot").unwrap();
         assert!(matches!(
-            (moonshot.status_fn)(&config),
+            moonshot.status(&config),
             IntegrationStatus::Active
         ));
 
         config.default_provider = Some("qwen-intl".to_string());
         let qwen = entries.iter().find(|e| e.name == "Qwen").unwrap();
-        assert!(matches!(
-            (qwen.status_fn)(&config),
-            IntegrationStatus::Active
-        ));
+        assert!(matches!(qwen.status(&config), IntegrationStatus::Active));
 
         config.default_provider = Some("zai-cn".to_string());
         let zai = entries.iter().find(|e| e.name == "Z.AI").unwrap();
-        assert!(matches!(
-            (zai.status_fn)(&config),
-            IntegrationStatus::Active
-        ));
+        assert!(matches!(zai.status(&config), IntegrationStatus::Active));
 
         config.default_provider = Some("baidu".to_string());
         let qianfan = entries.iter().find(|e| e.name == "Qianfan").unwrap();
-        assert!(matches!(
-            (qianfan.status_fn)(&config),
-            IntegrationStatus::Active
-        ));
+        assert!(matches!(qianfan.status(&config), IntegrationStatus::Active));
     }
 }
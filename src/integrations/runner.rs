@@ -0,0 +1,52 @@
+use super::IntegrationError;
+use anyhow::{Context, Result};
+use std::process::Command;
+
+/// Run `cmd` through the platform shell (`sh -c`, or `cmd /C` on Windows)
+/// for `integrations doctor --on-success-run`, inheriting stdio. A non-zero
+/// exit becomes [`IntegrationError::OnSuccessCommandFailed`] carrying the
+/// exit code, which the CLI exits with.
+pub fn on_success(cmd: &str) -> Result<()> {
+    let status = shell_command(cmd)
+        .status()
+        .with_context(|| format!("Failed to run on-success command `{cmd}`"))?;
+    if status.success() {
+        return Ok(());
+    }
+    Err(IntegrationError::OnSuccessCommandFailed {
+        command: cmd.to_string(),
+        code: status.code(),
+    }
+    .into())
+}
+
+#[cfg(target_os = "windows")]
+fn shell_command(cmd: &str) -> Command {
+    let mut command = Command::new("cmd");
+    command.arg("/C").arg(cmd);
+    command
+}
+
+#[cfg(not(target_os = "windows"))]
+fn shell_command(cmd: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.arg("-c").arg(cmd);
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn on_success_runs_command() {
+        on_success("echo ok").unwrap();
+    }
+
+    #[test]
+    fn on_success_reports_exit_code() {
+        let err = on_success("exit 3").unwrap_err();
+        let err = err.downcast_ref::<IntegrationError>().unwrap();
+        assert_eq!(err.exit_code(), Some(3));
+    }
+}
@@ -0,0 +1,130 @@
+use super::IntegrationEntry;
+use std::collections::HashSet;
+
+/// Default minimum similarity for a fuzzy `integrations search` hit
+pub const DEFAULT_THRESHOLD: f64 = 0.3;
+
+type Trigram = [char; 3];
+
+/// Lowercased character trigrams of `text`, padded like `pg_trgm` (two
+/// leading spaces, one trailing) so short words still produce some.
+fn trigrams(text: &str) -> HashSet<Trigram> {
+    let text = text.trim().to_lowercase();
+    if text.is_empty() {
+        return HashSet::new();
+    }
+    let padded: Vec<char> = "  ".chars().chain(text.chars()).chain([' ']).collect();
+    padded.windows(3).map(|w| [w[0], w[1], w[2]]).collect()
+}
+
+/// Jaccard similarity (0.0–1.0) of two trigram sets; 0.0 if either is empty.
+fn jaccard(a: &HashSet<Trigram>, b: &HashSet<Trigram>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 || a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let similarity = a.intersection(b).count() as f64 / union as f64;
+    similarity
+}
+
+/// Precomputed trigrams for each entry: its whole name plus every word of its
+/// name and description. An entry's score is its best-matching field, so a
+/// long description doesn't dilute a close match on one word.
+pub struct TrigramIndex<'a> {
+    docs: Vec<(&'a IntegrationEntry, Vec<HashSet<Trigram>>)>,
+}
+
+impl<'a> TrigramIndex<'a> {
+    pub fn new(entries: &[&'a IntegrationEntry]) -> Self {
+        let docs = entries
+            .iter()
+            .copied()
+            .map(|entry| {
+                let words = entry
+                    .name
+                    .split(|c: char| !c.is_alphanumeric())
+                    .chain(entry.description.split(|c: char| !c.is_alphanumeric()))
+                    .filter(|word| !word.is_empty());
+                let fields = std::iter::once(entry.name)
+                    .chain(words)
+                    .map(trigrams)
+                    .collect();
+                (entry, fields)
+            })
+            .collect();
+        Self { docs }
+    }
+
+    /// Entries scoring at least `threshold` against `query`, best first.
+    /// Ties keep registry order.
+    pub fn search(&self, query: &str, threshold: f64) -> Vec<(&'a IntegrationEntry, f64)> {
+        let query = trigrams(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut hits: Vec<_> = self
+            .docs
+            .iter()
+            .map(|(entry, fields)| {
+                let score = fields
+                    .iter()
+                    .map(|field| jaccard(&query, field))
+                    .fold(0.0, f64::max);
+                (*entry, score)
+            })
+            .filter(|(_, score)| *score > 0.0 && *score >= threshold)
+            .collect();
+        hits.sort_by(|a, b| b.1.total_cmp(&a.1));
+        hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::registry;
+
+    #[test]
+    fn trigrams_pad_and_lowercase() {
+        let grams = trigrams("Ab");
+        assert_eq!(grams.len(), 3);
+        assert!(grams.contains(&[' ', ' ', 'a']));
+        assert!(grams.contains(&[' ', 'a', 'b']));
+        assert!(grams.contains(&['a', 'b', ' ']));
+    }
+
+    #[test]
+    fn empty_query_has_no_trigrams_or_hits() {
+        assert!(trigrams("").is_empty());
+        assert!(trigrams("   ").is_empty());
+        assert_eq!(jaccard(&trigrams(""), &trigrams("telegram")), 0.0);
+
+        let entries = registry::all_integrations();
+        assert!(TrigramIndex::new(&entries).search("", 0.0).is_empty());
+    }
+
+    #[test]
+    fn single_character_query_only_matches_single_character_words() {
+        let single = trigrams("x");
+        assert_eq!(single.len(), 2);
+        assert_eq!(jaccard(&single, &trigrams("X")), 1.0);
+        assert!(jaccard(&single, &trigrams("xAI")) < DEFAULT_THRESHOLD);
+    }
+
+    #[test]
+    fn unicode_is_split_by_character_not_byte() {
+        assert_eq!(trigrams("ÉTÉ"), trigrams("été"));
+        assert_eq!(trigrams("日本語").len(), 4);
+        assert!(jaccard(&trigrams("zürich"), &trigrams("zurich")) > 0.0);
+    }
+
+    #[test]
+    fn typo_finds_closest_entry_first() {
+        let entries = registry::all_integrations();
+        let hits = TrigramIndex::new(&entries).search("telgram", DEFAULT_THRESHOLD);
+        assert_eq!(hits.first().map(|(e, _)| e.name), Some("Telegram"));
+        assert!(hits.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert!(hits.iter().all(|(_, score)| *score >= DEFAULT_THRESHOLD));
+    }
+}
@@ -0,0 +1,140 @@
+use super::doctor::{CheckReport, CheckResult};
+use anyhow::Result;
+use serde_json::{json, Value};
+
+/// Attachment colour when every check passed
+const PASS_COLOR: &str = "#2eb67d";
+/// Attachment colour when at least one check failed
+const FAIL_COLOR: &str = "#e01e5a";
+
+/// Post an `integrations doctor` summary to a Slack incoming webhook.
+pub async fn send_check_summary(webhook_url: &str, reports: &[CheckReport]) -> Result<()> {
+    crate::config::build_runtime_proxy_client("integrations.doctor.slack")
+        .post(webhook_url)
+        .json(&check_summary_payload(reports))
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        // Webhook URLs are secrets; keep them out of the error.
+        .map_err(reqwest::Error::without_url)?;
+    Ok(())
+}
+
+/// Block Kit payload: a headline plus one section per integration, inside an
+/// attachment coloured green when all checks passed and red otherwise.
+fn check_summary_payload(reports: &[CheckReport]) -> Value {
+    let failed = reports.iter().filter(|r| r.failed()).count();
+    let headline = if failed == 0 {
+        format!("✅ All {} integration checks passed", reports.len())
+    } else {
+        format!("❌ {failed} of {} integration checks failed", reports.len())
+    };
+
+    let mut blocks = vec![json!({
+        "type": "section",
+        "text": { "type": "mrkdwn", "text": format!("*{headline}*") },
+    })];
+    blocks.extend(reports.iter().map(|report| {
+        let line = match &report.result {
+            CheckResult::Passed => format!(
+                "✅ *{}* — passed in {} ms",
+                report.name,
+                report.latency.as_millis()
+            ),
+            CheckResult::Failed(reason) => format!("❌ *{}* — {reason}", report.name),
+            CheckResult::Skipped => format!("⚪ *{}* — no preflight check", report.name),
+        };
+        json!({ "type": "section", "text": { "type": "mrkdwn", "text": line } })
+    }));
+
+    json!({
+        "text": headline,
+        "attachments": [{
+            "color": if failed == 0 { PASS_COLOR } else { FAIL_COLOR },
+            "blocks": blocks,
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn report(name: &'static str, result: CheckResult) -> CheckReport {
+        CheckReport {
+            name,
+            result,
+            latency: Duration::from_millis(42),
+            cert_expires_in: None,
+        }
+    }
+
+    #[test]
+    fn payload_is_green_when_all_pass() {
+        let payload = check_summary_payload(&[
+            report("Telegram", CheckResult::Passed),
+            report("Ollama", CheckResult::Passed),
+        ]);
+        assert_eq!(payload["text"], "✅ All 2 integration checks passed");
+        let attachment = &payload["attachments"][0];
+        assert_eq!(attachment["color"], PASS_COLOR);
+        assert_eq!(attachment["blocks"].as_array().unwrap().len(), 3);
+        assert_eq!(
+            attachment["blocks"][1]["text"]["text"],
+            "✅ *Telegram* — passed in 42 ms"
+        );
+    }
+
+    #[test]
+    fn payload_is_red_when_any_fail() {
+        let payload = check_summary_payload(&[
+            report("Telegram", CheckResult::Passed),
+            report("Ollama", CheckResult::Failed("connection refused".into())),
+        ]);
+        assert_eq!(payload["attachments"][0]["color"], FAIL_COLOR);
+        assert_eq!(
+            payload["attachments"][0]["blocks"][2]["text"]["text"],
+            "❌ *Ollama* — connection refused"
+        );
+    }
+
+    #[tokio::test]
+    async fn send_check_summary_posts_to_webhook() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/services/T000/B000/XXXX"))
+            .and(body_partial_json(json!({
+                "attachments": [{ "color": FAIL_COLOR }],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let url = format!("{}/services/T000/B000/XXXX", server.uri());
+        send_check_summary(
+            &url,
+            &[report("Ollama", CheckResult::Failed("down".into()))],
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn send_check_summary_fails_on_error_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("no_service"))
+            .mount(&server)
+            .await;
+
+        let url = format!("{}/services/T000/B000/XXXX", server.uri());
+        let err = send_check_summary(&url, &[report("Telegram", CheckResult::Passed)])
+            .await
+            .unwrap_err();
+        assert!(!err.to_string().contains("XXXX"));
+    }
+}
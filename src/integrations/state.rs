@@ -0,0 +1,150 @@
+use crate::config::Config;
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How many recent checks [`StateStore::check_percentiles`] looks at
+const PERCENTILE_WINDOW: usize = 100;
+
+/// Latency percentiles over an integration's recent health checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Percentiles {
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+/// Integration health-check history in SQLite (`integrations.db`)
+pub struct StateStore {
+    conn: Connection,
+}
+
+impl StateStore {
+    /// Open (or create) the store at `path`.
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let conn =
+            Connection::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS health_checks (
+                id          INTEGER PRIMARY KEY AUTOINCREMENT,
+                name        TEXT NOT NULL,
+                duration_ms INTEGER NOT NULL,
+                passed      INTEGER NOT NULL,
+                checked_at  TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_health_checks_name ON health_checks(name, id);",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// Path of the store next to `config.toml` (`~/.zeroclaw/integrations.db`
+    /// by default).
+    pub fn path_for_config(config: &Config) -> PathBuf {
+        config
+            .config_path
+            .parent()
+            .map(PathBuf::from)
+            .unwrap_or_default()
+            .join("integrations.db")
+    }
+
+    /// Record one health check of `name` that took `duration`.
+    pub fn record_check(&self, name: &str, duration: Duration, passed: bool) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO health_checks (name, duration_ms, passed, checked_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                name,
+                i64::try_from(duration.as_millis()).unwrap_or(i64::MAX),
+                passed,
+                chrono::Utc::now().to_rfc3339(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Nearest-rank p50/p95/p99 of the last 100 check durations of `name`,
+    /// or `None` if it has never been checked.
+    pub fn check_percentiles(&self, name: &str) -> Option<Percentiles> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT duration_ms FROM health_checks
+                 WHERE name = ?1 ORDER BY id DESC LIMIT ?2",
+            )
+            .ok()?;
+        let mut durations = stmt
+            .query_map(params![name, PERCENTILE_WINDOW as i64], |row| {
+                row.get::<_, i64>(0)
+            })
+            .ok()?
+            .filter_map(std::result::Result::ok)
+            .map(|ms| u64::try_from(ms).unwrap_or(0))
+            .collect::<Vec<_>>();
+        if durations.is_empty() {
+            return None;
+        }
+        durations.sort_unstable();
+
+        let percentile = |p: usize| {
+            let rank = (p * durations.len()).div_ceil(100).max(1);
+            Duration::from_millis(durations[rank - 1])
+        };
+        Some(Percentiles {
+            p50: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn check_percentiles_over_known_durations() {
+        let dir = TempDir::new().unwrap();
+        let store = StateStore::open(&dir.path().join("integrations.db")).unwrap();
+        assert_eq!(store.check_percentiles("Telegram"), None);
+
+        for ms in [90, 10, 70, 30, 50, 100, 20, 80, 40, 60] {
+            store
+                .record_check("Telegram", Duration::from_millis(ms), true)
+                .unwrap();
+        }
+        store
+            .record_check("Ollama", Duration::from_secs(5), false)
+            .unwrap();
+
+        let percentiles = store.check_percentiles("Telegram").unwrap();
+        assert_eq!(percentiles.p50, Duration::from_millis(50));
+        assert_eq!(percentiles.p95, Duration::from_millis(100));
+        assert_eq!(percentiles.p99, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn check_percentiles_only_counts_last_hundred_checks() {
+        let dir = TempDir::new().unwrap();
+        let store = StateStore::open(&dir.path().join("integrations.db")).unwrap();
+        for _ in 0..50 {
+            store
+                .record_check("Ollama", Duration::from_secs(10), false)
+                .unwrap();
+        }
+        for _ in 0..PERCENTILE_WINDOW {
+            store
+                .record_check("Ollama", Duration::from_millis(5), true)
+                .unwrap();
+        }
+
+        let percentiles = store.check_percentiles("Ollama").unwrap();
+        assert_eq!(percentiles.p99, Duration::from_millis(5));
+    }
+}
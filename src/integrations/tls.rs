@@ -0,0 +1,311 @@
+use anyhow::{Context, Result};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio_rustls::rustls;
+use tokio_rustls::rustls::client::danger::{
+    HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier,
+};
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use tokio_rustls::rustls::{CertificateError, DigitallySignedStruct, SignatureScheme};
+
+/// `integrations doctor` warns when a certificate expires sooner than this
+pub const EXPIRY_WARNING: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// How far ahead [`ExpiryProbe`] looks for the end of a chain's validity.
+const MAX_LOOKAHEAD: Duration = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+
+/// Time left before the TLS certificate chain served at `url` expires, or
+/// `None` for non-HTTPS URLs. Goes through the runtime proxy like every other
+/// integrations request, and verifies the chain against the bundled web PKI
+/// roots. A chain that has already expired fails the handshake but is
+/// reported as [`Duration::ZERO`] rather than an error.
+pub async fn check_cert_expiry(url: &str) -> Result<Option<Duration>> {
+    let parsed = reqwest::Url::parse(url).with_context(|| format!("Invalid URL: {url}"))?;
+    if parsed.scheme() != "https" {
+        return Ok(None);
+    }
+
+    let root_store: rustls::RootCertStore =
+        webpki_roots::TLS_SERVER_ROOTS.iter().cloned().collect();
+    let webpki = rustls::client::WebPkiServerVerifier::builder(Arc::new(root_store)).build()?;
+    let probe = Arc::new(ExpiryProbe::new(webpki));
+    let tls_config = rustls::ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(probe.clone())
+        .with_no_client_auth();
+    let builder = reqwest::Client::builder()
+        .use_preconfigured_tls(tls_config)
+        .redirect(reqwest::redirect::Policy::none());
+    let client = crate::config::apply_runtime_proxy_to_builder(builder, "integrations.doctor.tls")
+        .build()?;
+
+    let response = client.head(parsed).send().await;
+    if let Some(remaining) = probe.remaining() {
+        return Ok(Some(remaining));
+    }
+    response?;
+    anyhow::bail!("Server sent no certificate")
+}
+
+/// Delegates verification to rustls' web PKI verifier and records how long
+/// the verified chain stays valid. Certificates are never parsed here: the
+/// expiry is found by re-running the verifier at later times.
+#[derive(Debug)]
+struct ExpiryProbe {
+    inner: Arc<dyn ServerCertVerifier>,
+    remaining: Mutex<Option<Duration>>,
+}
+
+impl ExpiryProbe {
+    fn new(inner: Arc<dyn ServerCertVerifier>) -> Self {
+        Self {
+            inner,
+            remaining: Mutex::new(None),
+        }
+    }
+
+    fn remaining(&self) -> Option<Duration> {
+        *self
+            .remaining
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn record(&self, remaining: Duration) {
+        *self
+            .remaining
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(remaining);
+    }
+
+    /// Longest offset from `now` (to the second, capped at [`MAX_LOOKAHEAD`])
+    /// at which the chain still verifies.
+    fn valid_for(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Duration {
+        let verifies_at = |offset: u64| {
+            let at = UnixTime::since_unix_epoch(Duration::from_secs(now.as_secs() + offset));
+            self.inner
+                .verify_server_cert(end_entity, intermediates, server_name, ocsp_response, at)
+                .is_ok()
+        };
+        let (mut valid, mut invalid) = (0, MAX_LOOKAHEAD.as_secs());
+        if verifies_at(invalid) {
+            return MAX_LOOKAHEAD;
+        }
+        while invalid - valid > 1 {
+            let mid = valid + (invalid - valid) / 2;
+            if verifies_at(mid) {
+                valid = mid;
+            } else {
+                invalid = mid;
+            }
+        }
+        Duration::from_secs(valid)
+    }
+}
+
+fn is_expired(error: &rustls::Error) -> bool {
+    matches!(
+        error,
+        rustls::Error::InvalidCertificate(
+            CertificateError::Expired | CertificateError::ExpiredContext { .. }
+        )
+    )
+}
+
+impl ServerCertVerifier for ExpiryProbe {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let verified = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        );
+        match &verified {
+            Ok(_) => self.record(self.valid_for(
+                end_entity,
+                intermediates,
+                server_name,
+                ocsp_response,
+                now,
+            )),
+            Err(error) if is_expired(error) => self.record(Duration::ZERO),
+            Err(_) => {}
+        }
+        verified
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// `⚠ TLS cert expires in N days` if `remaining` is under [`EXPIRY_WARNING`],
+/// or `⚠ TLS cert has expired` once it is zero.
+pub fn expiry_warning(remaining: Duration) -> Option<String> {
+    if remaining.is_zero() {
+        return Some("⚠ TLS cert has expired".to_string());
+    }
+    (remaining < EXPIRY_WARNING).then(|| {
+        format!(
+            "⚠ TLS cert expires in {} days",
+            remaining.as_secs() / (24 * 60 * 60)
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = 24 * 60 * 60;
+
+    /// Accepts any chain until `not_after` (seconds since the epoch), then
+    /// fails the way webpki does for an expired certificate.
+    #[derive(Debug)]
+    struct ExpiresAt {
+        not_after: u64,
+    }
+
+    impl ServerCertVerifier for ExpiresAt {
+        fn verify_server_cert(
+            &self,
+            _end_entity: &CertificateDer<'_>,
+            _intermediates: &[CertificateDer<'_>],
+            _server_name: &ServerName<'_>,
+            _ocsp_response: &[u8],
+            now: UnixTime,
+        ) -> Result<ServerCertVerified, rustls::Error> {
+            if now.as_secs() > self.not_after {
+                Err(rustls::Error::InvalidCertificate(CertificateError::Expired))
+            } else {
+                Ok(ServerCertVerified::assertion())
+            }
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            _message: &[u8],
+            _cert: &CertificateDer<'_>,
+            _dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            Ok(HandshakeSignatureValid::assertion())
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            _message: &[u8],
+            _cert: &CertificateDer<'_>,
+            _dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            Ok(HandshakeSignatureValid::assertion())
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            Vec::new()
+        }
+    }
+
+    fn verify_at(probe: &ExpiryProbe, now: u64) -> Result<ServerCertVerified, rustls::Error> {
+        probe.verify_server_cert(
+            &CertificateDer::from(vec![0x30, 0x00]),
+            &[],
+            &ServerName::try_from("example.com").unwrap(),
+            &[],
+            UnixTime::since_unix_epoch(Duration::from_secs(now)),
+        )
+    }
+
+    #[test]
+    fn probe_records_time_left_on_a_valid_chain() {
+        let now = 1_800_000_000;
+        let probe = ExpiryProbe::new(Arc::new(ExpiresAt {
+            not_after: now + 14 * DAY,
+        }));
+        assert_eq!(probe.remaining(), None);
+
+        assert!(verify_at(&probe, now).is_ok());
+        let remaining = probe.remaining().unwrap();
+        assert_eq!(remaining, Duration::from_secs(14 * DAY));
+        assert_eq!(
+            expiry_warning(remaining).as_deref(),
+            Some("⚠ TLS cert expires in 14 days")
+        );
+    }
+
+    #[test]
+    fn probe_reports_an_expired_chain_as_zero_and_still_fails_the_handshake() {
+        let now = 1_800_000_000;
+        let probe = ExpiryProbe::new(Arc::new(ExpiresAt {
+            not_after: now - DAY,
+        }));
+
+        let error = verify_at(&probe, now).unwrap_err();
+        assert!(is_expired(&error));
+        assert_eq!(probe.remaining(), Some(Duration::ZERO));
+        assert_eq!(
+            expiry_warning(Duration::ZERO).as_deref(),
+            Some("⚠ TLS cert has expired")
+        );
+    }
+
+    #[test]
+    fn probe_ignores_non_expiry_failures() {
+        assert!(!is_expired(&rustls::Error::InvalidCertificate(
+            CertificateError::UnknownIssuer
+        )));
+        assert!(is_expired(&rustls::Error::InvalidCertificate(
+            CertificateError::Expired
+        )));
+    }
+
+    #[test]
+    fn expiry_warning_only_inside_thirty_days() {
+        assert_eq!(expiry_warning(Duration::from_secs(45 * DAY)), None);
+        assert_eq!(
+            expiry_warning(Duration::from_secs(29 * DAY)).as_deref(),
+            Some("⚠ TLS cert expires in 29 days")
+        );
+    }
+
+    #[tokio::test]
+    async fn check_cert_expiry_skips_plain_http() {
+        assert_eq!(
+            check_cert_expiry("http://localhost:11434").await.unwrap(),
+            None
+        );
+        assert!(check_cert_expiry("not a url").await.is_err());
+    }
+}
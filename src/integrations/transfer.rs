@@ -0,0 +1,352 @@
+use super::{
+    config_key_is_set, find_integration, resolve_status, IntegrationCategory, IntegrationEntry,
+    IntegrationError, IntegrationStatus,
+};
+use crate::config::Config;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// One integration's activation state, as written by `integrations export`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedIntegration {
+    pub name: String,
+    pub category: IntegrationCategory,
+    pub status: IntegrationStatus,
+    /// Dotted config keys that have a value; never the values themselves
+    #[serde(default)]
+    pub config_keys_present: Vec<String>,
+}
+
+/// Snapshot `entries` against `config`.
+pub fn export(entries: &[&IntegrationEntry], config: &Config) -> Vec<ExportedIntegration> {
+    let config_json = serde_json::to_value(config).unwrap_or_default();
+    entries
+        .iter()
+        .map(|entry| ExportedIntegration {
+            name: entry.name.to_string(),
+            category: entry.category,
+            status: resolve_status(entry, config),
+            config_keys_present: config_keys(entry)
+                .filter(|key| config_key_is_set(&config_json, key))
+                .map(str::to_string)
+                .collect(),
+        })
+        .collect()
+}
+
+fn config_keys(entry: &IntegrationEntry) -> impl Iterator<Item = &'static str> {
+    entry
+        .required_config_keys
+        .iter()
+        .chain(entry.optional_config_keys)
+        .copied()
+}
+
+/// How importing one exported entry would change the live config
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportChange {
+    pub name: &'static str,
+    pub current: IntegrationStatus,
+    pub target: IntegrationStatus,
+    /// Keys set in the file but not here; their values have to be filled in by hand
+    pub keys_to_set: Vec<&'static str>,
+    /// Keys set here but not in the file; import removes them
+    pub keys_to_unset: Vec<&'static str>,
+    /// Keys set here but not in the file that can't be removed on their own
+    /// (e.g. a required `bot_token`); import leaves them for the user
+    pub keys_to_remove_by_hand: Vec<&'static str>,
+}
+
+impl ImportChange {
+    pub fn is_noop(&self) -> bool {
+        self.current == self.target
+            && self.keys_to_set.is_empty()
+            && self.keys_to_unset.is_empty()
+            && self.keys_to_remove_by_hand.is_empty()
+    }
+}
+
+/// Compare `exported` with the live registry and `config`. Fails on the first
+/// name that isn't in the registry.
+pub fn plan(exported: &[ExportedIntegration], config: &Config) -> Result<Vec<ImportChange>> {
+    let config_json = serde_json::to_value(config).unwrap_or_default();
+    let mut changes = Vec::with_capacity(exported.len());
+    for item in exported {
+        let entry = find_integration(&item.name)?;
+        let wanted = |key: &str| item.config_keys_present.iter().any(|k| k == key);
+        let (keys_to_set, unwanted): (Vec<_>, Vec<_>) = config_keys(entry)
+            .filter(|key| config_key_is_set(&config_json, key) != wanted(key))
+            .partition(|key| wanted(key));
+        let (keys_to_unset, keys_to_remove_by_hand) = unwanted
+            .into_iter()
+            .partition(|key| unset_key(config_json.clone(), key).is_ok());
+        changes.push(ImportChange {
+            name: entry.name,
+            current: resolve_status(entry, config),
+            target: item.status.clone(),
+            keys_to_set,
+            keys_to_unset,
+            keys_to_remove_by_hand,
+        });
+    }
+    Ok(changes)
+}
+
+/// Diff of `changes`: removed keys in red, keys needing a value in green,
+/// keys import won't touch in yellow.
+pub fn render_diff(changes: &[ImportChange]) -> String {
+    let mut out = String::new();
+    for change in changes.iter().filter(|c| !c.is_noop()) {
+        let header = if change.current == change.target {
+            format!("~ {}", change.name)
+        } else {
+            format!("~ {}: {} → {}", change.name, change.current, change.target)
+        };
+        let _ = writeln!(out, "{}", console::style(header).yellow().bold());
+        for key in &change.keys_to_unset {
+            let _ = writeln!(out, "{}", console::style(format!("  - {key}")).red());
+        }
+        for key in &change.keys_to_remove_by_hand {
+            let _ = writeln!(
+                out,
+                "{}",
+                console::style(format!("  - {key} (remove by hand from config.toml)")).yellow()
+            );
+        }
+        for key in &change.keys_to_set {
+            let _ = writeln!(
+                out,
+                "{}",
+                console::style(format!("  + {key} (needs a value in config.toml)")).green()
+            );
+        }
+    }
+    if out.is_empty() {
+        out.push_str("No changes.\n");
+    }
+    out
+}
+
+/// `config` with every key in `keys_to_unset` removed. Only the key itself
+/// goes; `keys_to_remove_by_hand` and enclosing sections are left alone.
+pub fn apply(config: &Config, changes: &[ImportChange]) -> Result<Config> {
+    let mut config_json = serde_json::to_value(config)?;
+    for key in changes.iter().flat_map(|c| &c.keys_to_unset) {
+        config_json = unset_key(config_json, key)?;
+    }
+    let mut updated: Config = serde_json::from_value(config_json)?;
+    updated.workspace_dir.clone_from(&config.workspace_dir);
+    updated.config_path.clone_from(&config.config_path);
+    Ok(updated)
+}
+
+fn unset_key(mut config_json: serde_json::Value, key: &str) -> Result<serde_json::Value> {
+    let parts: Vec<&str> = key.split('.').collect();
+    if !remove_path(&mut config_json, &parts) {
+        // Already absent; nothing to remove
+        return Ok(config_json);
+    }
+    if serde_json::from_value::<Config>(config_json.clone()).is_err() {
+        anyhow::bail!("Cannot unset {key}; remove it from config.toml by hand");
+    }
+    Ok(config_json)
+}
+
+/// Copy `config_path` to `<name>.<timestamp>.pre-import.bak` next to it
+/// before import rewrites it. `None` when there is no file to back up yet.
+pub fn backup(
+    config_path: &std::path::Path,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<Option<std::path::PathBuf>> {
+    if !config_path.exists() {
+        return Ok(None);
+    }
+    let file_name = config_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "config.toml".to_string());
+    let backup_path = config_path.with_file_name(format!(
+        "{file_name}.{}.pre-import.bak",
+        now.format("%Y%m%d%H%M%S")
+    ));
+    std::fs::copy(config_path, &backup_path)
+        .with_context(|| format!("Failed to back up {}", config_path.display()))?;
+    Ok(Some(backup_path))
+}
+
+fn remove_path(value: &mut serde_json::Value, path: &[&str]) -> bool {
+    let Some((last, parents)) = path.split_last() else {
+        return false;
+    };
+    parents
+        .iter()
+        .try_fold(value, |value, part| value.get_mut(*part))
+        .and_then(serde_json::Value::as_object_mut)
+        .is_some_and(|object| object.remove(*last).is_some())
+}
+
+/// Read an `integrations export` file.
+pub fn read(path: &std::path::Path) -> Result<Vec<ExportedIntegration>> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&raw).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Reject export formats other than JSON.
+pub fn check_format(format: Option<&str>) -> Result<(), IntegrationError> {
+    match format.map(str::to_lowercase).as_deref() {
+        None | Some("json") => Ok(()),
+        Some(other) => Err(IntegrationError::invalid_option(
+            "export format",
+            other,
+            &["json"],
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SlackConfig;
+    use crate::integrations::registry;
+
+    fn config_with_slack() -> Config {
+        let mut config = Config::default();
+        config.channels_config.slack = Some(SlackConfig {
+            bot_token: "xoxb-test".into(),
+            app_token: None,
+            channel_id: Some("C123".into()),
+            allowed_users: Vec::new(),
+            group_reply: None,
+        });
+        config
+    }
+
+    #[test]
+    fn export_lists_present_keys_without_values() {
+        let config = config_with_slack();
+        let exported = export(&registry::all_integrations(), &config);
+        let slack = exported.iter().find(|e| e.name == "Slack").unwrap();
+        assert_eq!(slack.status, IntegrationStatus::Active);
+        assert_eq!(
+            slack.config_keys_present,
+            [
+                "channels_config.slack.bot_token",
+                "channels_config.slack.channel_id",
+                "channels_config.slack.allowed_users",
+            ]
+        );
+        let json = serde_json::to_string(&exported).unwrap();
+        assert!(!json.contains("xoxb-test"));
+        let round_trip: Vec<ExportedIntegration> = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip, exported);
+    }
+
+    #[test]
+    fn plan_of_own_export_is_a_noop() {
+        let config = config_with_slack();
+        let exported = export(&registry::all_integrations(), &config);
+        let changes = plan(&exported, &config).unwrap();
+        assert!(changes.iter().all(ImportChange::is_noop));
+        assert_eq!(render_diff(&changes), "No changes.\n");
+    }
+
+    #[test]
+    fn plan_rejects_stale_names() {
+        let exported = vec![ExportedIntegration {
+            name: "Telegramm".into(),
+            category: IntegrationCategory::Chat,
+            status: IntegrationStatus::Available,
+            config_keys_present: Vec::new(),
+        }];
+        let err = plan(&exported, &Config::default()).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<IntegrationError>(),
+            Some(IntegrationError::UnknownIntegration { name, .. }) if name == "Telegramm"
+        ));
+    }
+
+    #[test]
+    fn import_unsets_keys_missing_from_the_file() {
+        let config = config_with_slack();
+        let exported = vec![ExportedIntegration {
+            name: "slack".into(),
+            category: IntegrationCategory::Chat,
+            status: IntegrationStatus::Available,
+            config_keys_present: vec![
+                "channels_config.slack.bot_token".into(),
+                "channels_config.slack.allowed_users".into(),
+            ],
+        }];
+        let changes = plan(&exported, &config).unwrap();
+        assert_eq!(
+            changes[0].keys_to_unset,
+            ["channels_config.slack.channel_id"]
+        );
+        assert!(changes[0].keys_to_set.is_empty());
+        let diff = console::strip_ansi_codes(&render_diff(&changes)).into_owned();
+        assert!(diff.contains("~ Slack: Active → Available"));
+        assert!(diff.contains("  - channels_config.slack.channel_id"));
+
+        let updated = apply(&config, &changes).unwrap();
+        let slack = updated.channels_config.slack.as_ref().unwrap();
+        assert_eq!(slack.bot_token, "xoxb-test");
+        assert_eq!(slack.channel_id, None);
+    }
+
+    #[test]
+    fn required_keys_are_left_for_removal_by_hand() {
+        let config = config_with_slack();
+        let exported = vec![ExportedIntegration {
+            name: "Slack".into(),
+            category: IntegrationCategory::Chat,
+            status: IntegrationStatus::Available,
+            config_keys_present: Vec::new(),
+        }];
+        let changes = plan(&exported, &config).unwrap();
+        assert_eq!(
+            changes[0].keys_to_remove_by_hand,
+            ["channels_config.slack.bot_token"]
+        );
+        assert!(!changes[0]
+            .keys_to_unset
+            .contains(&"channels_config.slack.bot_token"));
+        let diff = console::strip_ansi_codes(&render_diff(&changes)).into_owned();
+        assert!(diff.contains("  - channels_config.slack.bot_token (remove by hand"));
+
+        let updated = apply(&config, &changes).unwrap();
+        let slack = updated.channels_config.slack.as_ref().unwrap();
+        assert_eq!(slack.bot_token, "xoxb-test");
+        assert_eq!(slack.channel_id, None);
+        assert_eq!(updated.config_path, config.config_path);
+    }
+
+    #[test]
+    fn backup_copies_config_next_to_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let now = chrono::DateTime::parse_from_rfc3339("2026-10-16T09:30:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(backup(&path, now).unwrap(), None);
+
+        std::fs::write(&path, "api_key = \"k\"\n").unwrap();
+        let backup_path = backup(&path, now).unwrap().unwrap();
+        assert_eq!(
+            backup_path,
+            dir.path().join("config.toml.20261016093000.pre-import.bak")
+        );
+        assert_eq!(
+            std::fs::read_to_string(backup_path).unwrap(),
+            "api_key = \"k\"\n"
+        );
+    }
+
+    #[test]
+    fn check_format_accepts_only_json() {
+        assert!(check_format(None).is_ok());
+        assert!(check_format(Some("JSON")).is_ok());
+        assert!(check_format(Some("yaml")).is_err());
+    }
+}
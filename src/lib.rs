@@ -36,7 +36,7 @@
     dead_code
 )]
 
-use clap::Subcommand;
+use clap::{Args, Subcommand};
 use serde::{Deserialize, Serialize};
 
 pub mod agent;
@@ -59,7 +59,7 @@ pub(crate) mod identity;
 // Intentionally unused re-export — public API surface for plugin authors.
 #[allow(unused_imports)]
 pub(crate) mod plugins;
-pub(crate) mod integrations;
+pub mod integrations;
 pub mod memory;
 pub(crate) mod migration;
 pub(crate) mod multimodal;
@@ -361,24 +361,261 @@ pub enum MemoryCommands {
 #[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum IntegrationCommands {
     /// List all integrations (optionally filter by category or status)
-    List {
-        /// Filter by category (e.g. "chat", "ai", "productivity")
+    List(IntegrationListArgs),
+    /// Search integrations by keyword (matches name and description)
+    Search {
+        /// Search query (optional when using --load)
+        query: Option<String>,
+        /// Filter by category; comma-separate several (e.g. "chat", "chat,ai,tools")
         #[arg(long, short)]
         category: Option<String>,
-        /// Filter by status: active, available, coming-soon
+        /// Hide entries from these categories (e.g. "platforms", "social,media")
+        #[arg(long, value_name = "CATEGORY")]
+        category_exclude: Option<String>,
+        /// Filter by status: active, available, coming-soon, degraded,
+        /// auth-expired
         #[arg(long, short)]
         status: Option<String>,
-    },
-    /// Search integrations by keyword (matches name and description)
-    Search {
-        /// Search query
-        query: String,
+        /// Only match the query as a whole word (e.g. "ai" does not match "email")
+        #[arg(long)]
+        match_whole_word: bool,
+        /// Exit with an error when fewer than N integrations match (for CI checks)
+        #[arg(long, value_name = "N")]
+        min_results: Option<usize>,
+        /// Print matching entries as a JSON array
+        #[arg(long)]
+        json: bool,
+        /// One line per result (name, icon, status)
+        #[arg(long, conflicts_with_all = ["json", "verbose", "explain"])]
+        compact: bool,
+        /// Also show each result's category and set config keys
+        #[arg(long, conflicts_with = "json")]
+        verbose: bool,
+        /// Show why each result matched (name, description or tag)
+        #[arg(long, conflicts_with = "json")]
+        explain: bool,
+        /// Also rank trigram (typo-tolerant) matches below the exact ones.
+        /// Without it, fuzzy matching only kicks in when nothing matches exactly.
+        #[arg(long)]
+        fuzzy: bool,
+        /// Minimum fuzzy similarity, 0.0–1.0 (default: 0.3)
+        #[arg(long, value_name = "SCORE")]
+        fuzzy_threshold: Option<String>,
+        /// Save the query and filters as a named preset
+        #[arg(long, value_name = "NAME")]
+        save: Option<String>,
+        /// Replay a saved preset instead of a query
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["query", "category", "category_exclude", "status"])]
+        load: Option<String>,
     },
     /// Show details about a specific integration
     Info {
         /// Integration name
         name: String,
+        /// Show a side-by-side comparison with another integration
+        #[arg(long, value_name = "OTHER")]
+        compare: Option<String>,
+        /// Output format: text (default), html
+        #[arg(long, short, conflicts_with = "compare")]
+        output: Option<String>,
+    },
+    /// Run live connectivity checks for every configured integration
+    Doctor {
+        /// Per-integration timeout in seconds (default: 10)
+        #[arg(long)]
+        timeout_secs: Option<u64>,
+        /// Post a summary of the results to this Slack incoming webhook
+        #[arg(long, value_name = "WEBHOOK_URL")]
+        slack_notify: Option<String>,
+        /// Also write the results as a self-contained HTML page to this path
+        #[arg(long, value_name = "PATH")]
+        report_html: Option<std::path::PathBuf>,
+        /// Run this shell command when every check passes; its exit code
+        /// becomes the exit code of `doctor`
+        #[arg(long, value_name = "CMD")]
+        on_success_run: Option<String>,
+    },
+    /// Send a minimal live request to one integration and report the latency
+    #[command(long_about = "\
+Send a minimal live request to one integration (list models for AI
+providers, getMe for Telegram) and report the round-trip time. Works for
+integrations that aren't configured yet, so a token can be checked before
+it goes into config.toml. Exits non-zero on failure.
+
+Examples:
+  zeroclaw integrations test OpenRouter --api-key sk-or-...
+  zeroclaw integrations test Ollama")]
+    Test {
+        /// Integration name
+        name: String,
+        /// Credential to test instead of the configured one
+        #[arg(long)]
+        api_key: Option<String>,
+        /// Timeout in seconds (default: 15)
+        #[arg(long)]
+        timeout_secs: Option<u64>,
+    },
+    /// Exit non-zero if any required config key of an integration is unset
+    Validate {
+        /// Integration name
+        name: String,
+    },
+    /// Count integrations by category and status
+    Stats {
+        /// Print per-category counts as a JSON array
+        #[arg(long)]
+        json: bool,
+    },
+    /// Pin an integration to the top of `integrations list`
+    Pin {
+        /// Integration name
+        name: String,
+    },
+    /// Remove an integration from the pinned list
+    Unpin {
+        /// Integration name
+        name: String,
+    },
+    /// Write each integration's status and set config keys (no values)
+    Export {
+        /// Export format: json (default)
+        #[arg(long)]
+        format: Option<String>,
+        /// Write to this file instead of stdout
+        #[arg(long, short)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Apply an `integrations export` file to this config
+    Import {
+        /// File written by `integrations export`
+        #[arg(long)]
+        file: std::path::PathBuf,
+        /// Print the diff without changing config.toml
+        #[arg(long)]
+        dry_run: bool,
     },
+    /// Print a completion script for integration names (bash, zsh, fish)
+    #[command(long_about = "\
+Print a shell completion script that completes integration names after
+`zeroclaw integrations info`. Source it after `zeroclaw completions`.
+
+Examples:
+  source <(zeroclaw integrations completions bash)
+  zeroclaw integrations completions fish > ~/.config/fish/completions/zeroclaw_integrations.fish")]
+    Completions {
+        /// Target shell
+        #[arg(value_enum)]
+        #[serde(with = "crate::integrations::completions::shell_serde")]
+        shell: clap_complete::Shell,
+    },
+}
+
+/// Flags of `zeroclaw integrations list`
+#[derive(Args, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct IntegrationListArgs {
+    /// Filter by category; comma-separate several (e.g. "chat", "chat,ai,tools")
+    #[arg(long, short)]
+    pub category: Option<String>,
+    /// Filter by status: active, available, coming-soon, degraded,
+    /// auth-expired
+    #[arg(long, short)]
+    pub status: Option<String>,
+    /// Filter by service license: open-source, proprietary
+    #[arg(long)]
+    pub license: Option<String>,
+    /// Filter by maturity: stable, beta, experimental
+    #[arg(long, value_name = "MATURITY")]
+    pub filter_maturity: Option<String>,
+    /// Only show integrations carrying this tag (e.g. "self-hosted", "offline")
+    #[arg(long, value_name = "TAG")]
+    pub tag: Option<String>,
+    /// Filter by pricing model: free, freemium, paid-only, open-source
+    #[arg(long, value_name = "MODEL")]
+    pub pricing: Option<String>,
+    /// Only show integrations that run entirely on your own infrastructure
+    #[arg(long, conflicts_with = "no_self_hosted")]
+    pub self_hosted: bool,
+    /// Only show integrations that call a third-party service
+    #[arg(long)]
+    pub no_self_hosted: bool,
+    /// Filter by authentication: api-key, oauth2, bearer, basic, none
+    #[arg(long, value_name = "TYPE")]
+    pub auth_type: Option<String>,
+    /// Filter by where data is stored (e.g. "EU", "US", "Self-hosted")
+    #[arg(long, value_name = "REGION")]
+    pub data_residency: Option<String>,
+    /// Only integrations that handle this message type: text, image,
+    /// audio, video, file, reaction, sticker
+    #[arg(long, value_name = "TYPE")]
+    pub supports: Option<String>,
+    /// Output format: text (default), shell-array, prometheus, json, tsv,
+    /// compact (one line per integration), verbose. Defaults to
+    /// $ZEROCLAW_OUTPUT_FORMAT when set.
+    #[arg(long, short)]
+    pub output: Option<String>,
+    /// Print a header row with `--output tsv`
+    #[arg(long)]
+    pub headers: bool,
+    /// Print how many integrations would be shown instead of the list
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Keep re-rendering the list until Ctrl-C, reloading config each time
+    #[arg(long, conflicts_with_all = ["output", "json", "dry_run"])]
+    pub live: bool,
+    /// Seconds between `--live` refreshes
+    #[arg(long, value_name = "SECONDS", default_value_t = 30, requires = "live")]
+    pub interval: u64,
+    /// Print contributor diagnostics such as the registry source path
+    /// (also enabled by ZEROCLAW_DEBUG=1)
+    #[arg(long)]
+    pub debug: bool,
+    /// Write the list to this file (atomically) instead of stdout
+    #[arg(long, value_name = "PATH")]
+    pub output_file: Option<std::path::PathBuf>,
+    /// Show at most N entries in total, across all categories
+    #[arg(long, value_name = "N")]
+    pub max_entries: Option<usize>,
+    /// Group entries by: category (default), status, maturity
+    #[arg(long, value_name = "FIELD")]
+    pub group_by: Option<String>,
+    /// Show these categories first, in this order (e.g. "ai,chat,tools")
+    #[arg(long, value_name = "LIST")]
+    pub category_order: Option<String>,
+    /// Sort entries within each group by: name, category, status
+    /// (most configured first)
+    #[arg(long, value_name = "FIELD")]
+    pub sort_by: Option<String>,
+    /// Show a flat list instead of grouping (sorted by name unless
+    /// --sort-by is given)
+    #[arg(long, conflicts_with = "group_by")]
+    pub no_group: bool,
+    /// Hide the status icon on each row
+    #[arg(long)]
+    pub no_status: bool,
+    /// Include integrations that don't run on this operating system
+    #[arg(long)]
+    pub all_platforms: bool,
+    /// Print one line per category with active/total counts
+    #[arg(
+        long,
+        conflicts_with_all = ["category_only", "no_group", "group_by", "output", "json", "dry_run"]
+    )]
+    pub summary: bool,
+    /// Print only the category names, one per line
+    #[arg(long)]
+    pub category_only: bool,
+    /// Drop the blank lines between categories
+    #[arg(long)]
+    pub compact: bool,
+    /// Also show each entry's category and set config keys (same as
+    /// --output verbose)
+    #[arg(long, conflicts_with_all = ["output", "json"])]
+    pub verbose: bool,
+    /// Print matching entries and the registry checksum as JSON (same as
+    /// --output json)
+    #[arg(long, conflicts_with = "output")]
+    pub json: bool,
 }
 
 /// Hardware discovery subcommands
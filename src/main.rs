@@ -91,8 +91,8 @@ use config::Config;
 
 // Re-export so binary modules can use crate::<CommandEnum> while keeping a single source of truth.
 pub use zeroclaw::{
-    ChannelCommands, CronCommands, HardwareCommands, IntegrationCommands, MigrateCommands,
-    PeripheralCommands, ServiceCommands, SkillCommands,
+    ChannelCommands, CronCommands, HardwareCommands, IntegrationCommands, IntegrationListArgs,
+    MigrateCommands, PeripheralCommands, ServiceCommands, SkillCommands,
 };
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
@@ -1121,7 +1121,21 @@ async fn main() -> Result<()> {
 
         Commands::Integrations {
             integration_command,
-        } => integrations::handle_command(integration_command, &config),
+        } => match integrations::handle_command(integration_command, &config).await {
+            Err(e) => {
+                match e
+                    .downcast_ref::<integrations::IntegrationError>()
+                    .and_then(integrations::IntegrationError::exit_code)
+                {
+                    Some(code) => {
+                        eprintln!("Error: {e:#}");
+                        std::process::exit(code);
+                    }
+                    None => Err(e),
+                }
+            }
+            ok => ok,
+        },
 
         Commands::Skills { skill_command } => skills::handle_command(skill_command, &config),
 